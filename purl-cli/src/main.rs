@@ -0,0 +1,517 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+use purl_core::purl_batch::BatchEntry;
+use purl_core::purl_convert;
+use purl_core::purl_data::{self, Purl, PurlComponent};
+use purl_core::purl_eval;
+use purl_core::purl_lockfile::{self, LockfileFormat};
+use purl_core::purl_url;
+use purl_verify::purl_check;
+
+mod purl_batch;
+
+/// Validate, build and parse purls.
+#[derive(Parser)]
+#[command(name = "purl", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Evaluate each component of the purls, exiting non-zero if any falls short of the level.
+    Validate {
+        #[arg(required = true)]
+        purls: Vec<String>,
+        /// The level every component has to reach: verified, ok, valid or invalid.
+        #[arg(long, default_value = "valid")]
+        min_level: purl_eval::EvalResultLevel,
+        #[command(flatten)]
+        online: OnlineArgs,
+    },
+    /// Validate purls in bulk, printing a summary per type and writing a report per purl.
+    Batch {
+        /// Files with one purl per line, or CSV with --column; stdin if none (or '-') is given.
+        files: Vec<PathBuf>,
+        /// The CSV column holding the purls, by its header.
+        #[arg(long)]
+        column: Option<String>,
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Validate the purls of an SBOM (CycloneDX JSON or XML, SPDX JSON, YAML or tag-value),
+    /// reporting them by component.
+    Sbom {
+        /// The SBOM, or '-' for stdin.
+        file: PathBuf,
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Generate the purls of the packages a lockfile pins, and validate them.
+    Lockfile {
+        /// A Cargo.lock, package-lock.json, yarn.lock, pnpm-lock.yaml, poetry.lock,
+        /// requirements.txt or go.sum, or '-' for stdin.
+        file: PathBuf,
+        /// The format, by the usual file name of such lockfiles, if the file's name does not tell.
+        #[arg(long)]
+        format: Option<LockfileFormat>,
+        /// Only print the purls, one per line.
+        #[arg(long)]
+        list: bool,
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Canonicalize the purls of an SBOM, writing the corrected document along with a log of the
+    /// purls that changed.
+    Fix {
+        /// The SBOM, or '-' for stdin.
+        file: PathBuf,
+        /// Where to write the corrected document; stdout if not given, with the log on stderr.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Where to write the log of changes as JSON.
+        #[arg(long)]
+        changes_json: Option<PathBuf>,
+    },
+    /// Assemble a purl from its components, which are given unencoded.
+    Build {
+        #[arg(long = "type")]
+        typex: String,
+        /// Segments separated by '/', each unencoded like the other components (e.g. '@angular').
+        #[arg(long)]
+        namespace: Option<String>,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        version: Option<String>,
+        /// As they appear in the purl, e.g. 'arch=amd64&distro=bookworm'.
+        #[arg(long)]
+        qualifiers: Option<String>,
+        #[arg(long)]
+        subpath: Option<String>,
+    },
+    /// Turn a package reference of an ecosystem's own notation into a purl, e.g. Maven
+    /// coordinates, an npm package spec or a Cargo dependency line.
+    Convert { input: String },
+    /// Split a purl into its components, along with the package's web and download URLs.
+    Parse {
+        purl: String,
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Args)]
+struct OnlineArgs {
+    /// Check the purls against their registries.
+    #[arg(long)]
+    online: bool,
+    #[arg(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+    github_token: Option<String>,
+    #[arg(long)]
+    gitlab_base_url: Option<String>,
+    #[arg(long)]
+    huggingface_endpoint: Option<String>,
+    #[arg(long)]
+    deb_mirror: Option<String>,
+    #[arg(long)]
+    apk_mirror: Option<String>,
+    /// Look crates up in the sparse index rather than through the crates.io API.
+    #[arg(long)]
+    cratesio_sparse_index: bool,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// How many online checks to run at a time.
+    #[arg(long, default_value_t = 8)]
+    concurrency: usize,
+    /// Where to write the per-purl report as JSON.
+    #[arg(long)]
+    report_json: Option<PathBuf>,
+    /// Where to write the per-purl report as CSV.
+    #[arg(long)]
+    report_csv: Option<PathBuf>,
+    /// The level every purl has to reach: verified, ok, valid or invalid.
+    #[arg(long, default_value = "valid")]
+    min_level: purl_eval::EvalResultLevel,
+    #[command(flatten)]
+    online: OnlineArgs,
+}
+
+impl OnlineArgs {
+    fn check_options(&self) -> purl_check::CheckOptions {
+        purl_check::CheckOptions {
+            github_token: self.github_token.clone(),
+            gitlab_base_url: self.gitlab_base_url.clone(),
+            huggingface_endpoint: self.huggingface_endpoint.clone(),
+            deb_mirror: self.deb_mirror.clone(),
+            apk_mirror: self.apk_mirror.clone(),
+            cratesio_sparse_index: self.cratesio_sparse_index,
+            ..Default::default()
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Validate {
+            purls,
+            min_level,
+            online,
+        } => validate(&purls, &min_level, &online),
+        Command::Batch {
+            files,
+            column,
+            report,
+        } => batch(&files, column.as_deref(), &report),
+        Command::Sbom { file, report } => sbom(&file, &report),
+        Command::Lockfile {
+            file,
+            format,
+            list,
+            report,
+        } => lockfile(&file, format, list, &report),
+        Command::Fix {
+            file,
+            output,
+            changes_json,
+        } => fix(&file, output.as_deref(), changes_json.as_deref()),
+        Command::Build {
+            typex,
+            namespace,
+            name,
+            version,
+            qualifiers,
+            subpath,
+        } => build(Purl {
+            typex: purl_data::PurlType::new(&typex),
+            namespace: purl_data::PurlNamespace::new_naive(&namespace.unwrap_or_default()),
+            name: urlencoding::encode(&name).into_owned(),
+            version: version.map(|version| urlencoding::encode(&version).into_owned()),
+            qualifiers,
+            subpath,
+        }),
+        Command::Convert { input } => convert(&input),
+        Command::Parse { purl, json } => parse(&purl, json),
+    }
+}
+
+fn validate(
+    purls: &[String],
+    min_level: &purl_eval::EvalResultLevel,
+    online: &OnlineArgs,
+) -> ExitCode {
+    let options = online.check_options();
+    let mut all_good = true;
+    for (i, input) in purls.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{input}");
+        let purl = match Purl::parse(input) {
+            Ok(purl) => purl,
+            Err(e) => {
+                println!("  {e}");
+                all_good = false;
+                continue;
+            }
+        };
+
+        let (results, warnings, suggestions) = if online.online {
+            let online = futures::executor::block_on(purl_check::verify(&purl, &options));
+            if let Some(check) = &online.check {
+                println!("  checked against {check}");
+            }
+            (online.results, online.warnings, online.suggestions)
+        } else {
+            (purl_eval::PurlEvalResults::of(&purl), vec![], vec![])
+        };
+        for (component, result) in results.components() {
+            let level = result.level.to_string();
+            println!("  {component:<10} {level:<8} {}", result.explanation);
+        }
+        for warning in warnings {
+            println!("  warning: {warning}");
+        }
+        if !suggestions.is_empty() {
+            let names = suggestions
+                .iter()
+                .map(|suggestion| match &suggestion.namespace {
+                    Some(namespace) => format!("{namespace}/{}", suggestion.name),
+                    None => suggestion.name.clone(),
+                })
+                .collect::<Vec<_>>();
+            println!("  did you mean: {}", names.join(", "));
+        }
+        if results.worst_level().more_severe_than(min_level) {
+            all_good = false;
+        }
+    }
+
+    if all_good {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read_input(file: &Path) -> Option<String> {
+    let content = if file.as_os_str() == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map(|_| content)
+    } else {
+        std::fs::read_to_string(file)
+    };
+    content
+        .map_err(|e| eprintln!("could not read '{}': {e}", file.display()))
+        .ok()
+}
+
+fn batch(files: &[PathBuf], column: Option<&str>, args: &ReportArgs) -> ExitCode {
+    let stdin = [PathBuf::from("-")];
+    let mut entries = vec![];
+    for file in if files.is_empty() { &stdin } else { files } {
+        let Some(content) = read_input(file) else {
+            return ExitCode::FAILURE;
+        };
+        match purl_core::purl_batch::read_purls(&content, column) {
+            Ok(purls) => entries.extend(purls.iter().map(|purl| BatchEntry::evaluate(purl))),
+            Err(e) => {
+                eprintln!("could not read '{}': {e}", file.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    report(entries, args)
+}
+
+fn sbom(file: &Path, args: &ReportArgs) -> ExitCode {
+    let Some(content) = read_input(file) else {
+        return ExitCode::FAILURE;
+    };
+    match purl_core::purl_sbom::read(&content) {
+        Ok(components) => report(purl_core::purl_sbom::entries(&components), args),
+        Err(e) => {
+            eprintln!("could not read '{}': {e}", file.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn lockfile(
+    file: &Path,
+    format: Option<LockfileFormat>,
+    list: bool,
+    args: &ReportArgs,
+) -> ExitCode {
+    let Some(content) = read_input(file) else {
+        return ExitCode::FAILURE;
+    };
+    let format = format
+        .or_else(|| {
+            file.file_name()
+                .and_then(|name| LockfileFormat::of_file_name(&name.to_string_lossy()))
+        })
+        .or_else(|| LockfileFormat::sniff(&content));
+    let Some(format) = format else {
+        eprintln!(
+            "could not tell the format of '{}', try --format",
+            file.display()
+        );
+        return ExitCode::FAILURE;
+    };
+    let purls = match purl_lockfile::read(&content, format) {
+        Ok(purls) => purls,
+        Err(e) => {
+            eprintln!("could not read '{}' as {format}: {e}", file.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if list {
+        for purl in purls {
+            println!("{purl}");
+        }
+        return ExitCode::SUCCESS;
+    }
+    let entries = purls
+        .iter()
+        .map(|purl| BatchEntry::evaluate(&purl.to_string()))
+        .collect();
+    report(entries, args)
+}
+
+fn fix(file: &Path, output: Option<&Path>, changes_json: Option<&Path>) -> ExitCode {
+    let Some(content) = read_input(file) else {
+        return ExitCode::FAILURE;
+    };
+    let fixed = match purl_core::purl_fix::fix(&content) {
+        Ok(fixed) => fixed,
+        Err(e) => {
+            eprintln!("could not read '{}': {e}", file.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut log = String::new();
+    for change in &fixed.changes {
+        log += &format!(
+            "{}  {}  ->  {}\n",
+            change.reference, change.before, change.after
+        );
+    }
+    log += &format!("purls changed: {}\n", fixed.changes.len());
+
+    // with the document on stdout, the log goes to stderr
+    match output {
+        Some(output) => {
+            if let Err(e) = std::fs::write(output, &fixed.content) {
+                eprintln!("could not write '{}': {e}", output.display());
+                return ExitCode::FAILURE;
+            }
+            print!("{log}");
+        }
+        None => {
+            print!("{}", fixed.content);
+            eprint!("{log}");
+        }
+    }
+    if let Some(changes_json) = changes_json {
+        let changes = serde_json::to_string_pretty(&fixed.changes).unwrap_or_default();
+        if let Err(e) = std::fs::write(changes_json, changes + "\n") {
+            eprintln!("could not write '{}': {e}", changes_json.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Checks the entries online if asked to, then prints a summary along with the entries that fall
+/// short and writes the reports.
+fn report(entries: Vec<BatchEntry>, args: &ReportArgs) -> ExitCode {
+    let entries = purl_batch::check_online(
+        entries,
+        args.online.online.then_some(&args.online),
+        args.concurrency,
+    );
+    purl_batch::print_summary(&purl_core::purl_batch::BatchSummary::of(&entries));
+    purl_batch::print_findings(&entries, &args.min_level);
+
+    for (path, report) in [
+        (
+            &args.report_json,
+            purl_core::purl_batch::report_json as fn(&_) -> _,
+        ),
+        (&args.report_csv, purl_core::purl_batch::report_csv),
+    ] {
+        let Some(path) = path else {
+            continue;
+        };
+        if let Err(e) = std::fs::write(path, report(&entries)) {
+            eprintln!("could not write '{}': {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if entries
+        .iter()
+        .any(|entry| entry.level().more_severe_than(&args.min_level))
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn build(purl: Purl) -> ExitCode {
+    let results = purl_eval::PurlEvalResults::of(&purl);
+    let mut valid = true;
+    for (component, result) in results.components() {
+        if result.level == purl_eval::EvalResultLevel::Invalid {
+            eprintln!("{component}: {}", result.explanation);
+            valid = false;
+        }
+    }
+    if !valid {
+        return ExitCode::FAILURE;
+    }
+    println!("{purl}");
+    ExitCode::SUCCESS
+}
+
+fn convert(input: &str) -> ExitCode {
+    match purl_convert::convert(input) {
+        Some((notation, purl)) => {
+            eprintln!("read as {notation}");
+            println!("{purl}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("not a notation I know: {input}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse(input: &str, json: bool) -> ExitCode {
+    let purl = match Purl::parse(input) {
+        Ok(purl) => purl,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let decode = |s: &str| {
+        urlencoding::decode(s)
+            .map(|decoded| decoded.into_owned())
+            .unwrap_or_else(|_| s.to_string())
+    };
+    let qualifiers =
+        purl_data::PurlQualifiers::new_naive(purl.qualifiers.as_deref().unwrap_or_default());
+    let urls = purl_url::urls(&purl);
+
+    if json {
+        let value = serde_json::json!({
+            "type": purl.typex.to_string(),
+            "namespace": purl.namespace,
+            "name": decode(&purl.name),
+            "version": purl.version.as_deref().map(decode),
+            "qualifiers": qualifiers
+                .into_iter()
+                .map(|(key, value)| (key, serde_json::Value::String(value)))
+                .collect::<serde_json::Map<_, _>>(),
+            "subpath": purl.subpath,
+            "web_url": urls.web_url,
+            "download_url": urls.download_url,
+        });
+        println!("{value:#}");
+    } else {
+        println!("type:       {}", purl.typex);
+        println!("namespace:  {}", purl.namespace.join("/"));
+        println!("name:       {}", decode(&purl.name));
+        println!(
+            "version:    {}",
+            purl.version.as_deref().map(decode).unwrap_or_default()
+        );
+        for (key, value) in qualifiers {
+            println!("qualifier:  {key}={value}");
+        }
+        println!("subpath:    {}", purl.subpath.unwrap_or_default());
+        if let Some(web_url) = urls.web_url {
+            println!("web url:    {web_url}");
+        }
+        if let Some(download_url) = urls.download_url {
+            println!("download:   {download_url}");
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use purl_core::purl_batch::{BatchEntry, BatchSummary, LEVELS};
+use purl_core::purl_eval::EvalResultLevel;
+use purl_verify::purl_check;
+
+use crate::OnlineArgs;
+
+/// With `online`, checks the entries worth checking against their registries, running up to
+/// `concurrency` checks at a time.
+///
+/// The requests of the native client block, so the checks run on threads of their own, each
+/// with its own response cache.
+pub fn check_online(
+    entries: Vec<BatchEntry>,
+    online: Option<&OnlineArgs>,
+    concurrency: usize,
+) -> Vec<BatchEntry> {
+    let Some(online) = online else {
+        return entries;
+    };
+
+    let next = AtomicUsize::new(0);
+    let entries = entries.into_iter().map(Mutex::new).collect::<Vec<_>>();
+    std::thread::scope(|scope| {
+        for _ in 0..concurrency.max(1) {
+            scope.spawn(|| {
+                // the options are not Send, what they are made from is
+                let options = online.check_options();
+                while let Some(entry) = entries.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let mut entry = entry.lock().unwrap();
+                    let Ok(purl) = entry.purl.clone() else {
+                        continue;
+                    };
+                    let verification =
+                        futures::executor::block_on(purl_check::verify(&purl, &options));
+                    entry.results = Some(verification.results);
+                    entry.warnings.extend(verification.warnings);
+                }
+            });
+        }
+    });
+    entries
+        .into_iter()
+        .map(|entry| entry.into_inner().unwrap())
+        .collect()
+}
+
+/// Prints the counts per level, per type and overall.
+pub fn print_summary(summary: &BatchSummary) {
+    print!("{:<16}", "type");
+    for level in &LEVELS {
+        print!("{:>10}", level.to_string());
+    }
+    println!("{:>10}", "total");
+
+    let row = |label: &str, count: &dyn Fn(&EvalResultLevel) -> usize| {
+        print!("{label:<16}");
+        let mut total = 0;
+        for level in &LEVELS {
+            total += count(level);
+            print!("{:>10}", count(level));
+        }
+        println!("{total:>10}");
+    };
+    for typex in summary.types.keys() {
+        row(typex, &|level| summary.type_count(typex, level));
+    }
+    row("all", &|level| summary.count(level));
+}
+
+/// Prints the entries whose level is worse than the given one, and those with warnings.
+pub fn print_findings(entries: &[BatchEntry], min_level: &EvalResultLevel) {
+    let findings = entries
+        .iter()
+        .filter(|entry| entry.level().more_severe_than(min_level) || !entry.warnings.is_empty())
+        .collect::<Vec<_>>();
+    if findings.is_empty() {
+        return;
+    }
+    println!();
+    for entry in findings {
+        let label = entry.reference.as_deref().unwrap_or(&entry.input);
+        println!("{label}  {}  {}", entry.level(), entry.explanation());
+        if entry.reference.is_some() && !entry.input.is_empty() {
+            println!("  {}", entry.input);
+        }
+        for warning in &entry.warnings {
+            println!("  {warning}");
+        }
+    }
+}
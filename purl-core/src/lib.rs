@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod purl_batch;
+pub mod purl_convert;
+pub mod purl_cyclonedx;
+pub mod purl_data;
+pub mod purl_eval;
+pub mod purl_fix;
+pub mod purl_lockfile;
+pub mod purl_sbom;
+pub mod purl_snippet;
+pub mod purl_spdx;
+pub mod purl_suggest;
+pub mod purl_url;
+pub mod purl_version;
@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+use crate::purl_data::Purl;
+use crate::purl_eval::{EvalResultLevel, PurlEvalResults};
+
+/// The levels in the order summaries and reports list them.
+pub const LEVELS: [EvalResultLevel; 4] = [
+    EvalResultLevel::Verified,
+    EvalResultLevel::ProbablyOk,
+    EvalResultLevel::AtLeastValid,
+    EvalResultLevel::Invalid,
+];
+
+/// Reads the purls from newline-separated input (skipping empty lines and `#` comments) or, given
+/// a column, from CSV with a header row.
+pub fn read_purls(input: &str, column: Option<&str>) -> Result<Vec<String>, BatchInputErr> {
+    let Some(column) = column else {
+        return Ok(input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.to_string())
+            .collect());
+    };
+
+    let mut rows = input.lines().filter(|line| !line.trim().is_empty());
+    let header = split_csv_row(rows.next().ok_or(BatchInputErr::MissingHeader)?);
+    let index = header
+        .iter()
+        .position(|name| name.trim().eq_ignore_ascii_case(column.trim()))
+        .ok_or_else(|| BatchInputErr::UnknownColumn(column.to_string()))?;
+    Ok(rows
+        .filter_map(|row| split_csv_row(row).into_iter().nth(index))
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .collect())
+}
+
+/// Splits a CSV row into its fields, unquoting quoted ones (fields spanning lines are not
+/// supported, which purls never need).
+fn split_csv_row(row: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum BatchInputErr {
+    #[error("the CSV input has no header row")]
+    MissingHeader,
+    #[error("the CSV input has no column '{0}'")]
+    UnknownColumn(String),
+}
+
+/// One purl of a batch, along with what its evaluation found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    pub input: String,
+    /// What the purl belongs to where it comes from a document, e.g. a `bom-ref`.
+    pub reference: Option<String>,
+    pub purl: Result<Purl, String>,
+    pub results: Option<PurlEvalResults>,
+    /// Findings beyond the results, e.g. what kept an online check from completing.
+    pub warnings: Vec<String>,
+}
+
+impl BatchEntry {
+    /// Parses and evaluates the purl offline.
+    pub fn evaluate(input: &str) -> Self {
+        let purl = Purl::parse(input).map_err(|e| e.to_string());
+        let results = purl.as_ref().ok().map(PurlEvalResults::of);
+        BatchEntry {
+            input: input.to_string(),
+            reference: None,
+            purl,
+            results,
+            warnings: vec![],
+        }
+    }
+
+    /// The level of the most severe result, with purls that do not even parse being invalid.
+    pub fn level(&self) -> EvalResultLevel {
+        self.results
+            .as_ref()
+            .map(|results| results.worst_level())
+            .unwrap_or(EvalResultLevel::Invalid)
+    }
+
+    /// What the level is owed to: the explanations of the results at that level, or why the purl
+    /// does not parse.
+    pub fn explanation(&self) -> String {
+        let level = self.level();
+        match (&self.purl, &self.results) {
+            (Err(e), _) => e.clone(),
+            (Ok(_), Some(results)) => results
+                .components()
+                .into_iter()
+                .filter(|(_, result)| result.level == level)
+                .map(|(component, result)| format!("{component}: {}", result.explanation))
+                .collect::<Vec<_>>()
+                .join("; "),
+            (Ok(_), None) => String::new(),
+        }
+    }
+
+    pub fn type_name(&self) -> Option<String> {
+        self.purl.as_ref().ok().map(|purl| purl.typex.to_string())
+    }
+}
+
+/// How many purls of a batch reached which level, overall and per type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub levels: BTreeMap<EvalResultLevel, usize>,
+    /// By type, where purls that do not parse are listed under `-`.
+    pub types: BTreeMap<String, BTreeMap<EvalResultLevel, usize>>,
+}
+
+impl BatchSummary {
+    pub fn of(entries: &[BatchEntry]) -> Self {
+        let mut summary = BatchSummary::default();
+        for entry in entries {
+            let level = entry.level();
+            summary.total += 1;
+            *summary.levels.entry(level.clone()).or_default() += 1;
+            *summary
+                .types
+                .entry(entry.type_name().unwrap_or_else(|| "-".to_string()))
+                .or_default()
+                .entry(level)
+                .or_default() += 1;
+        }
+        summary
+    }
+
+    pub fn count(&self, level: &EvalResultLevel) -> usize {
+        self.levels.get(level).copied().unwrap_or_default()
+    }
+
+    pub fn type_count(&self, typex: &str, level: &EvalResultLevel) -> usize {
+        self.types
+            .get(typex)
+            .and_then(|levels| levels.get(level))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// The per-purl report as a JSON array.
+pub fn report_json(entries: &[BatchEntry]) -> String {
+    let report = entries
+        .iter()
+        .map(|entry| {
+            let components = entry
+                .results
+                .iter()
+                .flat_map(|results| results.components())
+                .map(|(component, result)| {
+                    (
+                        component.to_string(),
+                        serde_json::json!({
+                            "level": result.level.to_string(),
+                            "explanation": result.explanation,
+                        }),
+                    )
+                })
+                .collect::<serde_json::Map<_, _>>();
+            serde_json::json!({
+                "reference": entry.reference,
+                "input": entry.input,
+                "purl": entry.purl.as_ref().ok().map(|purl| purl.to_string()),
+                "type": entry.type_name(),
+                "level": entry.level().to_string(),
+                "error": entry.purl.as_ref().err(),
+                "components": components,
+                "warnings": entry.warnings,
+            })
+        })
+        .collect::<Vec<_>>();
+    format!("{:#}", serde_json::Value::Array(report))
+}
+
+/// The per-purl report as CSV, with a level and an explanation column per component.
+pub fn report_csv(entries: &[BatchEntry]) -> String {
+    let components = [
+        "type",
+        "namespace",
+        "name",
+        "version",
+        "qualifiers",
+        "subpath",
+    ];
+    let mut header = vec![
+        "reference",
+        "input",
+        "purl",
+        "purl_type",
+        "level",
+        "error",
+        "warnings",
+    ]
+    .into_iter()
+    .map(|column| column.to_string())
+    .collect::<Vec<_>>();
+    for component in components {
+        header.push(format!("{component}_level"));
+        header.push(format!("{component}_explanation"));
+    }
+
+    let mut csv = header.join(",") + "\n";
+    for entry in entries {
+        let mut row = vec![
+            entry.reference.clone().unwrap_or_default(),
+            entry.input.clone(),
+            entry
+                .purl
+                .as_ref()
+                .map(|purl| purl.to_string())
+                .unwrap_or_default(),
+            entry.type_name().unwrap_or_default(),
+            entry.level().to_string(),
+            entry.purl.as_ref().err().cloned().unwrap_or_default(),
+            entry.warnings.join("; "),
+        ];
+        match &entry.results {
+            Some(results) => {
+                for (_, result) in results.components() {
+                    row.push(result.level.to_string());
+                    row.push(result.explanation.clone());
+                }
+            }
+            None => row.extend(
+                components
+                    .iter()
+                    .flat_map(|_| [String::new(), String::new()]),
+            ),
+        }
+        csv += &row
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv += "\n";
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_purls, report_csv, BatchEntry, BatchInputErr, BatchSummary};
+    use crate::purl_eval::EvalResultLevel;
+
+    #[test]
+    fn test_read_purls() {
+        assert_eq!(
+            read_purls("pkg:cargo/serde\n\n# comment\n  pkg:npm/left-pad  \n", None),
+            Ok(vec![
+                "pkg:cargo/serde".to_string(),
+                "pkg:npm/left-pad".to_string()
+            ])
+        );
+        assert_eq!(
+            read_purls(
+                "Name,PURL\r\n\"serde, the crate\",pkg:cargo/serde\r\nleft-pad,\"pkg:npm/left-pad\"\r\nnone,\r\n",
+                Some("purl")
+            ),
+            Ok(vec![
+                "pkg:cargo/serde".to_string(),
+                "pkg:npm/left-pad".to_string()
+            ])
+        );
+        assert_eq!(
+            read_purls("name\nserde\n", Some("purl")),
+            Err(BatchInputErr::UnknownColumn("purl".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let entries =
+            ["pkg:cargo/serde@1.0.0", "pkg:cargo/Serde Json", "serde"].map(BatchEntry::evaluate);
+        let summary = BatchSummary::of(&entries);
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.count(&EvalResultLevel::ProbablyOk), 2);
+        assert_eq!(summary.count(&EvalResultLevel::Invalid), 1);
+        assert_eq!(summary.count(&EvalResultLevel::AtLeastValid), 0);
+        assert_eq!(summary.type_count("cargo", &EvalResultLevel::ProbablyOk), 2);
+        assert_eq!(summary.type_count("cargo", &EvalResultLevel::Invalid), 0);
+        assert_eq!(summary.type_count("-", &EvalResultLevel::Invalid), 1);
+    }
+
+    #[test]
+    fn test_report_csv_quotes_fields() {
+        let csv = report_csv(&[BatchEntry::evaluate("pkg:npm/a?x=1,2")]);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.starts_with(",\"pkg:npm/a?x=1,2\",\"pkg:npm/a?x=1,2\",npm,"));
+    }
+}
@@ -0,0 +1,382 @@
+use std::fmt;
+
+use crate::purl_data::{Purl, PurlType};
+
+lazy_static! {
+    // `serde = "1.0"`, `serde = { version = "1.0", features = ["derive"] }`
+    static ref CARGO_DEPENDENCY_REGEX: regex::Regex =
+        regex::Regex::new(r#"^([A-Za-z0-9_-]+)\s*=\s*(".*"|\{.*\})$"#).unwrap();
+
+    // `requests[security]==2.31.0; python_version >= "3.7"`
+    static ref PIP_REQUIREMENT_REGEX: regex::Regex =
+        regex::Regex::new(r"^([A-Za-z0-9][A-Za-z0-9._-]*)\s*(\[[^\]]*\])?\s*(===|==|~=|>=|<=|!=|>|<)\s*([^;,\s]+)").unwrap();
+
+    // `nginx:1.25@sha256:...`, `gcr.io/distroless/static:nonroot`
+    static ref DOCKER_REFERENCE_REGEX: regex::Regex =
+        regex::Regex::new(r"^([a-z0-9][a-z0-9._:-]*/)*[a-z0-9][a-z0-9._-]*(:[A-Za-z0-9_][A-Za-z0-9._-]*)?(@sha256:[a-f0-9]+)?$").unwrap();
+
+    static ref MAVEN_PART_REGEX: regex::Regex =
+        regex::Regex::new(r"^[A-Za-z0-9._-]+$").unwrap();
+
+    // a complete `x.y.z[-pre][+build]`, anything shorter is a range to Cargo and npm
+    static ref SEMVER_REGEX: regex::Regex =
+        regex::Regex::new(r"^\d+\.\d+\.\d+(-[0-9A-Za-z.-]+)?(\+[0-9A-Za-z.-]+)?$").unwrap();
+
+    static ref PYPI_NAME_SEPARATORS_REGEX: regex::Regex =
+        regex::Regex::new(r"[-_.]+").unwrap();
+}
+
+/// The notations packages are commonly referred to with in their ecosystems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Purl,
+    MavenCoordinates,
+    NpmSpec,
+    CargoDependency,
+    PipRequirement,
+    DockerReference,
+    GoModRequire,
+}
+
+impl fmt::Display for Notation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Notation::Purl => "purl",
+            Notation::MavenCoordinates => "Maven coordinates",
+            Notation::NpmSpec => "npm package spec",
+            Notation::CargoDependency => "Cargo dependency",
+            Notation::PipRequirement => "pip requirement",
+            Notation::DockerReference => "Docker image reference",
+            Notation::GoModRequire => "go.mod requirement",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Recognizes the notation of a package reference and converts it into a purl, e.g.
+/// `org.apache.commons:commons-lang3:3.12.0` into `pkg:maven/org.apache.commons/commons-lang3@3.12.0`.
+///
+/// Only exact versions make it into the purl, version requirements (like `^1.0` or `>=2`) are
+/// left out.
+pub fn convert(input: &str) -> Option<(Notation, Purl)> {
+    let input = input.trim();
+    if input.is_empty() || input.contains('\n') {
+        return None;
+    }
+    if input.starts_with("pkg:") {
+        return Some((Notation::Purl, Purl::parse(input).ok()?));
+    }
+    [
+        (Notation::GoModRequire, go_mod_require as fn(&str) -> _),
+        (Notation::CargoDependency, cargo_dependency),
+        (Notation::PipRequirement, pip_requirement),
+        (Notation::NpmSpec, npm_spec),
+        (Notation::MavenCoordinates, maven_coordinates),
+        (Notation::DockerReference, docker_reference),
+    ]
+    .into_iter()
+    .find_map(|(notation, convert)| Some((notation, convert(input)?)))
+}
+
+/// The version if the requirement pins one: a complete version, possibly prefixed with `=`.
+/// Cargo would read a bare `1.0.0` as `^1.0.0`, but it is the version that is most likely meant;
+/// a bare `1.0` or `1` on the other hand is too far from naming a version.
+fn exact_version(requirement: &str) -> Option<&str> {
+    let version = requirement.trim();
+    let version = version.strip_prefix('=').unwrap_or(version).trim();
+    SEMVER_REGEX.is_match(version).then_some(version)
+}
+
+/// `github.com/google/uuid v1.5.0`, optionally with `require` and `// indirect`, or the
+/// `github.com/google/uuid@v1.5.0` of `go get`.
+fn go_mod_require(input: &str) -> Option<Purl> {
+    let input = input.trim_start_matches("require ").trim();
+    let input = input.split("//").next().unwrap_or_default().trim();
+    let (module, version) = match input.split_once(char::is_whitespace) {
+        Some((module, version)) => (module, version.trim()),
+        None => input.split_once('@')?,
+    };
+    let is_module = module.contains('/')
+        && module
+            .split('/')
+            .next()
+            .is_some_and(|host| host.contains('.'))
+        && !module.starts_with('@');
+    let is_version =
+        version.starts_with('v') && version[1..].starts_with(|c: char| c.is_ascii_digit());
+    (is_module && is_version)
+        .then(|| Purl::from_parts(PurlType::Golang, module, Some(version), vec![]))
+}
+
+/// A line of the `[dependencies]` of a Cargo.toml, where a `package` key names the crate a
+/// dependency is renamed from.
+fn cargo_dependency(input: &str) -> Option<Purl> {
+    let captures = CARGO_DEPENDENCY_REGEX.captures(input)?;
+    let mut name = captures[1].to_string();
+    let value = &captures[2];
+    let field = |key: &str| {
+        regex::Regex::new(&format!(r#"\b{key}\s*=\s*"([^"]*)""#))
+            .ok()?
+            .captures(value)
+            .map(|captures| captures[1].to_string())
+    };
+
+    let (version, qualifiers) = if value.starts_with('"') {
+        (Some(value.trim_matches('"').to_string()), vec![])
+    } else {
+        if let Some(package) = field("package") {
+            name = package;
+        }
+        let qualifiers = match field("git") {
+            Some(git) => {
+                let commit = field("rev").or_else(|| field("tag"));
+                let vcs_url = match commit {
+                    Some(commit) => format!("git+{git}@{commit}"),
+                    None => format!("git+{git}"),
+                };
+                vec![("vcs_url", vcs_url)]
+            }
+            None => vec![],
+        };
+        (field("version"), qualifiers)
+    };
+    Some(Purl::from_parts(
+        PurlType::Cargo,
+        &name,
+        version.as_deref().and_then(exact_version),
+        qualifiers,
+    ))
+}
+
+/// A requirement with a version specifier, as a bare name could be anything.
+fn pip_requirement(input: &str) -> Option<Purl> {
+    let captures = PIP_REQUIREMENT_REGEX.captures(input)?;
+    let version = match &captures[3] {
+        "==" | "===" => Some(&captures[4]).filter(|version| !version.contains('*')),
+        _ => None,
+    };
+    // the purl spec asks for the name as normalized by PEP 503
+    let name = PYPI_NAME_SEPARATORS_REGEX
+        .replace_all(&captures[1], "-")
+        .to_lowercase();
+    Some(Purl::from_parts(PurlType::Pypi, &name, version, vec![]))
+}
+
+/// `left-pad@1.3.0` or `@angular/core@^17.0.0`, but not the `name@sha256:...` of Docker.
+fn npm_spec(input: &str) -> Option<Purl> {
+    let (name, version) = match input.char_indices().skip(1).find(|(_, c)| *c == '@') {
+        Some((i, _)) => (&input[..i], Some(&input[i + 1..])),
+        None => (input, None),
+    };
+    let is_scoped = name.starts_with('@') && name.matches('/').count() == 1;
+    let is_unscoped = version.is_some() && !name.contains(['/', ':']);
+    if !(is_scoped || is_unscoped) || version.is_some_and(|version| version.contains(':')) {
+        return None;
+    }
+    Some(Purl::from_parts(
+        PurlType::Npm,
+        name,
+        version.and_then(exact_version),
+        vec![],
+    ))
+}
+
+/// `groupId:artifactId[:packaging[:classifier]]:version` as Maven prints them (possibly followed
+/// by the scope, as in `mvn dependency:list`), or just `groupId:artifactId`.
+fn maven_coordinates(input: &str) -> Option<Purl> {
+    let mut parts = input.split(':').collect::<Vec<_>>();
+    if parts.iter().any(|part| !MAVEN_PART_REGEX.is_match(part)) {
+        return None;
+    }
+    const SCOPES: [&str; 6] = ["compile", "provided", "runtime", "test", "system", "import"];
+    if parts.len() >= 5 && SCOPES.contains(parts.last()?) {
+        parts.pop();
+    }
+    let (group, artifact, packaging, classifier, version) = match parts[..] {
+        // tell 'org.example:artifact' from a Docker image with a tag
+        [group, artifact] if group.contains('.') => (group, artifact, None, None, None),
+        [group, artifact, version] => (group, artifact, None, None, Some(version)),
+        [group, artifact, packaging, version] => {
+            (group, artifact, Some(packaging), None, Some(version))
+        }
+        [group, artifact, packaging, classifier, version] => (
+            group,
+            artifact,
+            Some(packaging),
+            Some(classifier),
+            Some(version),
+        ),
+        _ => return None,
+    };
+
+    let mut qualifiers = vec![];
+    if let Some(classifier) = classifier {
+        qualifiers.push(("classifier", classifier.to_string()));
+    }
+    if let Some(packaging) = packaging.filter(|packaging| *packaging != "jar") {
+        qualifiers.push(("type", packaging.to_string()));
+    }
+    Some(Purl::from_parts(
+        PurlType::Maven,
+        &format!("{group}/{artifact}"),
+        version,
+        qualifiers,
+    ))
+}
+
+/// `[registry/][namespace/]name[:tag][@digest]`, with a tag or digest to tell it from other
+/// paths. The digest is the version if there is one, the tag otherwise.
+fn docker_reference(input: &str) -> Option<Purl> {
+    if !DOCKER_REFERENCE_REGEX.is_match(input) {
+        return None;
+    }
+    let (reference, digest) = match input.split_once('@') {
+        Some((reference, digest)) => (reference, Some(digest)),
+        None => (input, None),
+    };
+    // a ':' after the last '/' separates the tag, one before it is the port of the registry
+    let (path, tag) = match reference.rsplit_once(':') {
+        Some((path, tag)) if !tag.contains('/') => (path, Some(tag)),
+        _ => (reference, None),
+    };
+    if tag.is_none() && digest.is_none() {
+        return None;
+    }
+
+    let (registry, path) = match path.split_once('/') {
+        Some((registry, path)) if registry.contains(['.', ':']) || registry == "localhost" => {
+            (Some(registry), path)
+        }
+        _ => (None, path),
+    };
+    let mut qualifiers = vec![];
+    if let Some(registry) =
+        registry.filter(|registry| !["docker.io", "index.docker.io"].contains(registry))
+    {
+        qualifiers.push(("repository_url", registry.to_string()));
+    }
+    if let (Some(tag), Some(_)) = (tag, digest) {
+        qualifiers.push(("tag", tag.to_string()));
+    }
+    Some(Purl::from_parts(
+        PurlType::Docker,
+        path,
+        digest.or(tag),
+        qualifiers,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, Notation};
+
+    use paste::paste;
+
+    macro_rules! test_convert {
+        ($name:ident, $input:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_convert_ $name>]() {
+                let input = $input;
+                let expected: Option<(Notation, &str)> = $expect;
+                let result = convert(input).map(|(notation, purl)| (notation, purl.to_string()));
+                if result != expected.map(|(notation, purl)| (notation, purl.to_string())) {
+                    panic!("converting '{input}' expects {expected:?} got {result:?}")
+                }
+            }
+            }
+        };
+    }
+
+    test_convert!(
+        maven,
+        "org.apache.commons:commons-lang3:3.12.0",
+        Some((
+            Notation::MavenCoordinates,
+            "pkg:maven/org.apache.commons/commons-lang3@3.12.0"
+        ))
+    );
+    test_convert!(
+        maven_dependency_list,
+        "org.lwjgl:lwjgl:jar:natives-linux:3.3.3:runtime",
+        Some((
+            Notation::MavenCoordinates,
+            "pkg:maven/org.lwjgl/lwjgl@3.3.3?classifier=natives-linux"
+        ))
+    );
+    test_convert!(
+        npm_scoped,
+        "@angular/core@17.0.0",
+        Some((Notation::NpmSpec, "pkg:npm/%40angular/core@17.0.0"))
+    );
+    test_convert!(
+        npm_range,
+        "left-pad@^1.3.0",
+        Some((Notation::NpmSpec, "pkg:npm/left-pad"))
+    );
+    test_convert!(
+        cargo,
+        r#"serde = "1.0""#,
+        Some((Notation::CargoDependency, "pkg:cargo/serde"))
+    );
+    test_convert!(
+        cargo_complete_version,
+        r#"serde = "1.0.195""#,
+        Some((Notation::CargoDependency, "pkg:cargo/serde@1.0.195"))
+    );
+    test_convert!(
+        cargo_major_only,
+        r#"foo = { version = "1" }"#,
+        Some((Notation::CargoDependency, "pkg:cargo/foo"))
+    );
+    test_convert!(
+        cargo_renamed,
+        r#"json = { package = "serde_json", version = "=1.0.111", optional = true }"#,
+        Some((Notation::CargoDependency, "pkg:cargo/serde_json@1.0.111"))
+    );
+    test_convert!(
+        pip,
+        "requests[security]==2.31.0 ; python_version >= \"3.7\"",
+        Some((Notation::PipRequirement, "pkg:pypi/requests@2.31.0"))
+    );
+    test_convert!(
+        pip_range,
+        "Django>=4.2",
+        Some((Notation::PipRequirement, "pkg:pypi/django"))
+    );
+    test_convert!(
+        pip_normalized,
+        "Foo.Bar__baz===1.0",
+        Some((Notation::PipRequirement, "pkg:pypi/foo-bar-baz@1.0"))
+    );
+    test_convert!(
+        docker,
+        "nginx:1.25@sha256:4c0fdaa8b6341bfdeca5f18f7837462c80cff90527ee35ef185571e1c327beac",
+        Some((
+            Notation::DockerReference,
+            "pkg:docker/nginx@sha256%3A4c0fdaa8b6341bfdeca5f18f7837462c80cff90527ee35ef185571e1c327beac?tag=1.25"
+        ))
+    );
+    test_convert!(
+        docker_registry,
+        "gcr.io/distroless/static:nonroot",
+        Some((
+            Notation::DockerReference,
+            "pkg:docker/distroless/static@nonroot?repository_url=gcr.io"
+        ))
+    );
+    test_convert!(
+        go_mod,
+        "github.com/foo/bar v1.2.3 // indirect",
+        Some((
+            Notation::GoModRequire,
+            "pkg:golang/github.com/foo/bar@v1.2.3"
+        ))
+    );
+    test_convert!(
+        purl,
+        "pkg:cargo/serde@1.0.195",
+        Some((Notation::Purl, "pkg:cargo/serde@1.0.195"))
+    );
+    test_convert!(unknown, "just some words", None);
+}
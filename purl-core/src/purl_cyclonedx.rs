@@ -0,0 +1,204 @@
+use crate::purl_sbom::SbomComponent;
+
+/// Whether the document looks like a CycloneDX BOM, in JSON or XML.
+pub fn is_cyclonedx(content: &str) -> bool {
+    let content = content.trim_start();
+    (content.starts_with('{') && content.contains("\"bomFormat\""))
+        || (content.starts_with('<') && content.contains("cyclonedx.org/schema/bom"))
+}
+
+/// Reads the components of a CycloneDX BOM (including the one the BOM describes and nested
+/// ones), in JSON or XML.
+pub fn read(content: &str) -> Result<Vec<SbomComponent>, CyclonedxErr> {
+    let components = if content.trim_start().starts_with('<') {
+        read_xml(content)?
+    } else {
+        let bom: CyclonedxBom =
+            serde_json::from_str(content).map_err(|e| CyclonedxErr::InvalidJson(e.to_string()))?;
+        bom.metadata
+            .and_then(|metadata| metadata.component)
+            .into_iter()
+            .chain(bom.components)
+            .collect()
+    };
+
+    let mut flattened = vec![];
+    flatten(components, &mut flattened);
+    Ok(flattened)
+}
+
+fn flatten(components: Vec<CyclonedxComponent>, into: &mut Vec<SbomComponent>) {
+    for component in components {
+        into.push(SbomComponent {
+            reference: component
+                .bom_ref
+                .clone()
+                .unwrap_or_else(|| component.name.clone()),
+            group: component.group.filter(|group| !group.is_empty()),
+            name: Some(component.name),
+            version: component.version.filter(|version| !version.is_empty()),
+            purls: component.purl.into_iter().collect(),
+        });
+        flatten(component.components, into);
+    }
+}
+
+fn read_xml(content: &str) -> Result<Vec<CyclonedxComponent>, CyclonedxErr> {
+    let document =
+        roxmltree::Document::parse(content).map_err(|e| CyclonedxErr::InvalidXml(e.to_string()))?;
+    let root = document.root_element();
+
+    fn child<'a, 'input>(
+        node: roxmltree::Node<'a, 'input>,
+        name: &str,
+    ) -> Option<roxmltree::Node<'a, 'input>> {
+        node.children()
+            .find(|child| child.is_element() && child.tag_name().name() == name)
+    }
+    fn text(node: roxmltree::Node<'_, '_>, name: &str) -> Option<String> {
+        child(node, name)
+            .and_then(|child| child.text())
+            .map(|text| text.trim().to_string())
+    }
+    fn components_of<'a, 'input: 'a>(
+        node: roxmltree::Node<'a, 'input>,
+    ) -> impl Iterator<Item = roxmltree::Node<'a, 'input>> {
+        node.children()
+            .filter(|child| child.is_element() && child.tag_name().name() == "components")
+            .flat_map(|components| components.children())
+            .filter(|component| {
+                component.is_element() && component.tag_name().name() == "component"
+            })
+    }
+    fn component(node: roxmltree::Node<'_, '_>) -> CyclonedxComponent {
+        CyclonedxComponent {
+            bom_ref: node.attribute("bom-ref").map(|bom_ref| bom_ref.to_string()),
+            group: text(node, "group"),
+            name: text(node, "name").unwrap_or_default(),
+            version: text(node, "version"),
+            purl: text(node, "purl"),
+            components: components_of(node).map(component).collect(),
+        }
+    }
+
+    Ok(child(root, "metadata")
+        .and_then(|metadata| child(metadata, "component"))
+        .into_iter()
+        .chain(components_of(root))
+        .map(component)
+        .collect())
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum CyclonedxErr {
+    #[error("not a valid CycloneDX JSON document ({0})")]
+    InvalidJson(String),
+    #[error("not a valid CycloneDX XML document ({0})")]
+    InvalidXml(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CyclonedxBom {
+    // intentionally abbreviated
+    // {
+    //   "bomFormat": "CycloneDX",
+    //   "specVersion": "1.5",
+    //   "metadata": { "component": { "bom-ref": "my-app", "name": "my-app", ... }, ... },
+    pub metadata: Option<CyclonedxMetadata>,
+    //   "components": [ { "bom-ref": "pkg:npm/left-pad@1.3.0", "name": "left-pad", ... }, ... ],
+    #[serde(default)]
+    pub components: Vec<CyclonedxComponent>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CyclonedxMetadata {
+    // intentionally abbreviated
+    // {
+    //   "timestamp": "2024-01-25T12:00:00Z",
+    //   "component": { "type": "application", "name": "my-app", ... },
+    pub component: Option<CyclonedxComponent>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CyclonedxComponent {
+    // intentionally abbreviated
+    // {
+    //   "type": "library",
+    //   "bom-ref": "pkg:maven/org.apache.commons/commons-lang3@3.14.0",
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: Option<String>,
+    //   "group": "org.apache.commons",
+    pub group: Option<String>,
+    //   "name": "commons-lang3",
+    pub name: String,
+    //   "version": "3.14.0",
+    pub version: Option<String>,
+    //   "purl": "pkg:maven/org.apache.commons/commons-lang3@3.14.0",
+    pub purl: Option<String>,
+    //   "components": [ ... ],
+    #[serde(default)]
+    pub components: Vec<CyclonedxComponent>,
+    // }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    #[test]
+    fn test_read_json() {
+        let bom = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "metadata": { "component": { "type": "application", "bom-ref": "app", "name": "app" } },
+            "components": [
+                {
+                    "type": "library",
+                    "bom-ref": "lang3",
+                    "group": "org.apache.commons",
+                    "name": "commons-lang3",
+                    "version": "3.14.0",
+                    "purl": "pkg:maven/org.apache.commons/commons-lang3@3.14.0",
+                    "components": [ { "type": "library", "name": "nested", "version": "1.0" } ]
+                }
+            ]
+        }"#;
+        let components = read(bom).unwrap();
+        assert_eq!(
+            components
+                .iter()
+                .map(|component| (component.reference.as_str(), component.purls.len()))
+                .collect::<Vec<_>>(),
+            vec![("app", 0), ("lang3", 1), ("nested", 0)]
+        );
+        assert_eq!(components[2].version.as_deref(), Some("1.0"));
+    }
+
+    #[test]
+    fn test_read_xml() {
+        let bom = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+              <components>
+                <component type="library" bom-ref="left-pad">
+                  <name>left-pad</name>
+                  <version>1.3.0</version>
+                  <purl>pkg:npm/left-pad@1.3.0</purl>
+                  <components>
+                    <component type="library"><group>@angular</group><name>core</name></component>
+                  </components>
+                </component>
+              </components>
+            </bom>"#;
+        let components = read(bom).unwrap();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].reference, "left-pad");
+        assert_eq!(
+            components[0].purls,
+            vec!["pkg:npm/left-pad@1.3.0".to_string()]
+        );
+        assert_eq!(components[1].group.as_deref(), Some("@angular"));
+        assert!(components[1].purls.is_empty());
+    }
+}
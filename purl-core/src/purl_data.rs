@@ -460,3 +460,329 @@ impl PurlComponent for PurlNamespace {
         clean_but_reversed // now just clean
     }
 }
+
+pub type PurlQualifiers = Vec<(String, String)>;
+
+impl PurlComponent for PurlQualifiers {
+    fn new_naive(s: &str) -> Self {
+        s.split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) => (
+                    key.to_string(),
+                    urlencoding::decode(value)
+                        .map(|v| v.into_owned())
+                        .unwrap_or_else(|_| value.to_string()),
+                ),
+                None => (pair.to_string(), String::new()),
+            })
+            .collect()
+    }
+
+    fn as_canonical(&self) -> Self {
+        // keys are case-insensitive and sorted, pairs with empty values are to be discarded
+        let mut canonical: PurlQualifiers = self
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| (key.to_lowercase(), value.clone()))
+            .collect();
+        canonical.sort_by(|(a, _), (b, _)| a.cmp(b));
+        canonical
+    }
+}
+
+/// Looks up the value of the qualifier `key` (compared case-insensitively, as keys are).
+pub fn qualifier_value<'a>(qualifiers: &'a PurlQualifiers, key: &str) -> Option<&'a str> {
+    qualifiers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, value)| value.as_str())
+        .filter(|value| !value.is_empty())
+}
+
+/// Turns a `repository_url` qualifier value (which may or may not have a scheme) into a base URL
+/// without trailing slashes, suitable for building API URLs from.
+pub fn repository_url_as_base_url(repository_url: &str) -> String {
+    let trimmed = repository_url.trim().trim_end_matches('/');
+    if trimmed.contains("://") {
+        trimmed.to_string()
+    } else {
+        format!("https://{trimmed}")
+    }
+}
+
+/// A complete purl, with its components held the way the builder holds them: the namespace
+/// decoded into its segments, name and version percent-encoded, qualifiers and subpath as they
+/// appear in the purl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Purl {
+    pub typex: PurlType,
+    pub namespace: PurlNamespace,
+    pub name: String,
+    pub version: Option<String>,
+    pub qualifiers: Option<String>,
+    pub subpath: Option<String>,
+}
+
+impl Purl {
+    /// Splits a purl string into its components (leniently, e.g. accepting `pkg://` and an
+    /// uppercase type), leaving the judgement of the components to the evaluation.
+    pub fn parse(s: &str) -> Result<Purl, PurlParseErr> {
+        let s = s.trim();
+        let (s, subpath) = match s.split_once('#') {
+            Some((s, subpath)) => (s, Some(subpath.trim_matches('/').to_string())),
+            None => (s, None),
+        };
+        let (s, qualifiers) = match s.split_once('?') {
+            Some((s, qualifiers)) => (s, Some(qualifiers.to_string())),
+            None => (s, None),
+        };
+        let s = match s.split_once(':') {
+            Some((scheme, s)) if scheme.eq_ignore_ascii_case("pkg") => s.trim_start_matches('/'),
+            _ => return Err(PurlParseErr::MissingScheme),
+        };
+        let (typex, path) = match s.split_once('/') {
+            Some(("", _)) => return Err(PurlParseErr::MissingType),
+            Some((typex, path)) => (typex, path.trim_end_matches('/')),
+            None => return Err(PurlParseErr::MissingName),
+        };
+        let (namespace, name_and_version) = match path.rsplit_once('/') {
+            Some((namespace, name_and_version)) => (namespace, name_and_version),
+            None => ("", path),
+        };
+        // the '@' of an (unencoded) npm scope is in the namespace, so only the last segment
+        // can hold the version
+        let (name, version) = match name_and_version.rsplit_once('@') {
+            Some((name, version)) => (name, Some(version.to_string())),
+            None => (name_and_version, None),
+        };
+        if name.is_empty() {
+            return Err(PurlParseErr::MissingName);
+        }
+
+        Ok(Purl {
+            typex: PurlType::new(&typex.to_lowercase()),
+            namespace: namespace
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| {
+                    urlencoding::decode(segment)
+                        .map(|decoded| decoded.into_owned())
+                        .unwrap_or_else(|_| segment.to_string())
+                })
+                .collect(),
+            name: name.to_string(),
+            version,
+            qualifiers: qualifiers.filter(|qualifiers| !qualifiers.is_empty()),
+            subpath: subpath.filter(|subpath| !subpath.is_empty()),
+        })
+    }
+
+    /// Assembles a purl from unencoded parts, the namespace being what precedes the last '/' of
+    /// the full name (e.g. `@angular/core` or `github.com/google/uuid`).
+    pub fn from_parts(
+        typex: PurlType,
+        full_name: &str,
+        version: Option<&str>,
+        qualifiers: Vec<(&str, String)>,
+    ) -> Purl {
+        let (namespace, name) = full_name.rsplit_once('/').unwrap_or(("", full_name));
+        let qualifiers = qualifiers
+            .iter()
+            .map(|(key, value)| format!("{key}={}", urlencoding::encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        Purl {
+            typex,
+            namespace: PurlNamespace::new_naive(namespace),
+            name: urlencoding::encode(name).into_owned(),
+            version: version
+                .filter(|version| !version.is_empty())
+                .map(|version| urlencoding::encode(version).into_owned()),
+            qualifiers: Some(qualifiers).filter(|qualifiers| !qualifiers.is_empty()),
+            subpath: None,
+        }
+    }
+
+    /// The purl in canonical form: namespace and qualifiers canonicalized, everything encoded the
+    /// same way, and the names of the types that require it normalized (e.g. lowercase GitHub
+    /// repos, PyPI names with '-' rather than '_').
+    pub fn as_canonical(&self) -> Purl {
+        let decode = |s: &str| {
+            urlencoding::decode(s)
+                .map(|decoded| decoded.into_owned())
+                .unwrap_or_else(|_| s.to_string())
+        };
+        // ':' needs no encoding anywhere in a purl
+        let encode = |s: &str| urlencoding::encode(s).replace("%3A", ":");
+
+        let mut namespace = self.namespace.as_canonical();
+        let mut name = decode(&self.name);
+        match self.typex {
+            PurlType::Bitbucket | PurlType::Composer | PurlType::Github | PurlType::Hex => {
+                namespace = namespace.iter().map(|s| s.to_lowercase()).collect();
+                name = name.to_lowercase();
+            }
+            PurlType::Pypi => name = name.to_lowercase().replace('_', "-"),
+            _ => {}
+        }
+
+        let qualifiers = PurlQualifiers::new_naive(self.qualifiers.as_deref().unwrap_or_default())
+            .as_canonical()
+            .iter()
+            .map(|(key, value)| format!("{key}={}", encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let subpath = self
+            .subpath
+            .as_deref()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !matches!(*segment, "" | "." | ".."))
+            .map(|segment| encode(&decode(segment)))
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Purl {
+            typex: PurlType::new(&self.typex.to_string().to_lowercase()),
+            namespace,
+            name: encode(&name),
+            version: self
+                .version
+                .as_deref()
+                .map(|version| encode(&decode(version))),
+            qualifiers: Some(qualifiers).filter(|qualifiers| !qualifiers.is_empty()),
+            subpath: Some(subpath).filter(|subpath| !subpath.is_empty()),
+        }
+    }
+}
+
+impl fmt::Display for Purl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "pkg:{}/", self.typex)?;
+        for segment in self.namespace.as_canonical() {
+            write!(f, "{}/", urlencoding::encode(&segment))?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, "@{version}")?;
+        }
+        if let Some(qualifiers) = &self.qualifiers {
+            write!(f, "?{qualifiers}")?;
+        }
+        if let Some(subpath) = &self.subpath {
+            write!(f, "#{subpath}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum PurlParseErr {
+    #[error("a purl has to start with 'pkg:'")]
+    MissingScheme,
+    #[error("the type is missing")]
+    MissingType,
+    #[error("the name is missing")]
+    MissingName,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Purl, PurlComponent, PurlNamespace, PurlParseErr, PurlType};
+
+    use paste::paste;
+
+    macro_rules! test_roundtrip {
+        ($name:ident, $purl:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_roundtrip_ $name>]() {
+                let purl = $purl;
+                let expected = $expect;
+                let result = Purl::parse(purl).map(|parsed| parsed.to_string());
+                if result != expected {
+                    panic!("parsing '{purl}' expects {expected:?} got {result:?}")
+                }
+            }
+            }
+        };
+    }
+
+    test_roundtrip!(
+        github,
+        "pkg:github/package-url/purl-spec@244fd47e07d1004f0aed9c",
+        Ok("pkg:github/package-url/purl-spec@244fd47e07d1004f0aed9c".to_string())
+    );
+    test_roundtrip!(
+        npm_scope,
+        "pkg:npm/%40angular/animation@12.3.1",
+        Ok("pkg:npm/%40angular/animation@12.3.1".to_string())
+    );
+    test_roundtrip!(
+        npm_unencoded_scope,
+        "pkg:npm/@angular/animation",
+        Ok("pkg:npm/%40angular/animation".to_string())
+    );
+    test_roundtrip!(
+        everything,
+        "pkg://Maven/org.apache.xmlgraphics/batik-anim@1.9.1?classifier=sources#/src/main/",
+        Ok(
+            "pkg:maven/org.apache.xmlgraphics/batik-anim@1.9.1?classifier=sources#src/main"
+                .to_string()
+        )
+    );
+    test_roundtrip!(
+        no_scheme,
+        "cargo/serde@1.0.0",
+        Err(PurlParseErr::MissingScheme)
+    );
+    test_roundtrip!(no_name, "pkg:cargo", Err(PurlParseErr::MissingName));
+
+    #[test]
+    fn test_display_encodes_namespace() {
+        // the namespace is held decoded, as entered, and only encoded on the way out
+        let purl = Purl {
+            typex: PurlType::Generic,
+            namespace: PurlNamespace::new_naive("@scope/a?b#c"),
+            name: "name".to_string(),
+            version: None,
+            qualifiers: None,
+            subpath: None,
+        };
+        assert_eq!(purl.to_string(), "pkg:generic/%40scope/a%3Fb%23c/name");
+        assert_eq!(Purl::parse(&purl.to_string()).unwrap(), purl);
+    }
+
+    #[test]
+    fn test_as_canonical() {
+        let canonical = |purl: &str| Purl::parse(purl).unwrap().as_canonical().to_string();
+        assert_eq!(
+            canonical("pkg:PyPI/Django_Rest@1.0?Repository_URL=https://example.com/simple&arch="),
+            "pkg:pypi/django-rest@1.0?repository_url=https:%2F%2Fexample.com%2Fsimple"
+        );
+        assert_eq!(
+            canonical("pkg:github/Package-URL/Purl-Spec#./docs/../spec"),
+            "pkg:github/package-url/purl-spec#docs/spec"
+        );
+        assert_eq!(
+            canonical("pkg:npm/@angular/core@17.0.0?b=2&a=1"),
+            "pkg:npm/%40angular/core@17.0.0?a=1&b=2"
+        );
+        assert_eq!(
+            canonical("pkg:maven/org.example/a%2Db@1.0"),
+            "pkg:maven/org.example/a-b@1.0"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let purl = Purl::parse("pkg:deb/debian/curl@7.50.3-1?arch=i386&distro=jessie").unwrap();
+        assert_eq!(purl.typex, PurlType::Deb);
+        assert_eq!(purl.namespace, vec!["debian".to_string()]);
+        assert_eq!(purl.name, "curl");
+        assert_eq!(purl.version.as_deref(), Some("7.50.3-1"));
+        assert_eq!(purl.qualifiers.as_deref(), Some("arch=i386&distro=jessie"));
+        assert_eq!(purl.subpath, None);
+    }
+}
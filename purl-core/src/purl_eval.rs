@@ -31,6 +31,23 @@ impl std::fmt::Display for EvalResultLevel {
     }
 }
 
+impl std::str::FromStr for EvalResultLevel {
+    type Err = String;
+
+    /// Accepts what the level is displayed as.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "verified" => Ok(EvalResultLevel::Verified),
+            "ok" => Ok(EvalResultLevel::ProbablyOk),
+            "valid" => Ok(EvalResultLevel::AtLeastValid),
+            "invalid" => Ok(EvalResultLevel::Invalid),
+            other => Err(format!(
+                "unknown level '{other}' (expected verified, ok, valid or invalid)"
+            )),
+        }
+    }
+}
+
 impl EvalResultLevel {
     pub fn more_severe_than(&self, other: &EvalResultLevel) -> bool {
         self > other
@@ -87,6 +104,150 @@ impl EvalResult {
     }
 }
 
+/// What a registry has to say about a package (and the purl's version of it) beyond its mere
+/// existence, as far as the verifiers get to see it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryMetadata {
+    /// Where the metadata comes from, e.g. `crates.io`.
+    pub source: String,
+    pub latest_version: Option<String>,
+    pub license: Option<String>,
+    pub description: Option<String>,
+    /// When the purl's version was published.
+    pub published: Option<chrono::DateTime<chrono::Utc>>,
+    /// Caveats concerning the purl's version.
+    pub warnings: Vec<VersionWarning>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionWarning {
+    Yanked,
+    Deprecated(Option<String>),
+    Retracted,
+    /// Holds the registry's description of the retirement.
+    Retired(String),
+    Prerelease,
+    Draft,
+}
+
+impl std::fmt::Display for VersionWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionWarning::Yanked => write!(f, "yanked"),
+            VersionWarning::Deprecated(None) => write!(f, "deprecated"),
+            VersionWarning::Deprecated(Some(message)) => write!(f, "deprecated: {message}"),
+            VersionWarning::Retracted => write!(f, "retracted"),
+            VersionWarning::Retired(retirement) => write!(f, "{retirement}"),
+            VersionWarning::Prerelease => write!(f, "a pre-release"),
+            VersionWarning::Draft => write!(f, "a draft"),
+        }
+    }
+}
+
+impl VersionWarning {
+    /// Whether the warning means the version should not be used, as opposed to merely being
+    /// worth knowing about.
+    pub fn is_serious(&self) -> bool {
+        matches!(
+            self,
+            VersionWarning::Yanked
+                | VersionWarning::Deprecated(_)
+                | VersionWarning::Retracted
+                | VersionWarning::Retired(_)
+        )
+    }
+}
+
+impl RegistryMetadata {
+    /// Downgrades a `Verified` version result to `ProbablyOk` if the version is yanked, deprecated,
+    /// retracted or retired, naming the reason in the explanation.
+    pub fn adjust_version_result(&self, result: EvalResult) -> EvalResult {
+        let serious_warnings = self
+            .warnings
+            .iter()
+            .filter(|warning| warning.is_serious())
+            .map(|warning| warning.to_string())
+            .collect::<Vec<_>>();
+        if result.level != EvalResultLevel::Verified || serious_warnings.is_empty() {
+            return result;
+        }
+        EvalResult {
+            level: EvalResultLevel::ProbablyOk,
+            explanation: format!(
+                "{}, but {}",
+                result.explanation,
+                serious_warnings.join(" and ")
+            ),
+        }
+    }
+}
+
+/// The (offline) evaluation of each component of a purl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurlEvalResults {
+    pub typex: EvalResult,
+    pub namespace: EvalResult,
+    pub name: EvalResult,
+    pub version: EvalResult,
+    pub qualifiers: EvalResult,
+    pub subpath: EvalResult,
+}
+
+impl PurlEvalResults {
+    pub fn of(purl: &purl_data::Purl) -> Self {
+        PurlEvalResults {
+            typex: eval_purl_type(purl.typex.clone()),
+            namespace: eval_purl_namespace(purl.namespace.clone(), purl.typex.clone()),
+            name: eval_purl_name(
+                purl.name.clone(),
+                purl.namespace.clone(),
+                purl.typex.clone(),
+            ),
+            version: eval_purl_version(
+                purl.typex.clone(),
+                purl.namespace.clone(),
+                purl.name.clone(),
+                purl.version.clone(),
+            ),
+            qualifiers: eval_purl_qualifiers(purl.qualifiers.clone()),
+            subpath: eval_purl_subpath(purl.subpath.clone()),
+        }
+    }
+
+    /// The results along with the names of the components they are for.
+    pub fn components(&self) -> [(&'static str, &EvalResult); 6] {
+        [
+            ("type", &self.typex),
+            ("namespace", &self.namespace),
+            ("name", &self.name),
+            ("version", &self.version),
+            ("qualifiers", &self.qualifiers),
+            ("subpath", &self.subpath),
+        ]
+    }
+
+    /// The level of the most severe result.
+    pub fn worst_level(&self) -> EvalResultLevel {
+        self.components()
+            .into_iter()
+            .map(|(_, result)| result.level.clone())
+            .max()
+            .unwrap_or(EvalResultLevel::Verified)
+    }
+
+    /// Whether type, namespace and name are good enough to bother checking online, which is what
+    /// the builder does as well.
+    pub fn worth_checking_online(&self) -> bool {
+        [&self.typex, &self.namespace, &self.name]
+            .iter()
+            .all(|result| {
+                result
+                    .level
+                    .at_least_as_good_as(&EvalResultLevel::ProbablyOk)
+            })
+    }
+}
+
 pub fn eval_purl_type(purl_type: PurlType) -> EvalResult {
     match purl_type.status() {
         PurlTypeStatus::WellKnown => EvalResult {
@@ -201,7 +362,7 @@ pub fn eval_purl_namespace(
 
 pub fn eval_purl_name(
     purl_name: String,
-    purl_namespace: purl_data::PurlNamespace,
+    _purl_namespace: purl_data::PurlNamespace,
     typex: purl_data::PurlType,
 ) -> EvalResult {
     // sanity check: ensure valid percent-encoding
@@ -220,7 +381,6 @@ pub fn eval_purl_name(
         };
     }
 
-    let canonical_namespace = purl_namespace.as_canonical();
     let mut findings = vec![];
 
     match typex {
@@ -267,8 +427,8 @@ pub fn eval_purl_version(
                     ),
                 },
                 Ok(decoded) => {
-                    let decoded = decoded.into_owned();
-                    let mut findings = vec![];
+                    let _decoded = decoded.into_owned();
+                    let findings = vec![];
                     // TODO
                     EvalResult::aggregate(&findings)
                 }
@@ -283,8 +443,8 @@ pub fn eval_purl_qualifiers(qualifiers: Option<String>) -> EvalResult {
             level: EvalResultLevel::ProbablyOk,
             explanation: "nothing to check on qualifiers".to_string(),
         },
-        Some(s) => {
-            let mut findings = vec![];
+        Some(_s) => {
+            let findings = vec![];
             EvalResult::aggregate(&findings)
         }
     }
@@ -296,8 +456,8 @@ pub fn eval_purl_subpath(subpath: Option<String>) -> EvalResult {
             level: EvalResultLevel::ProbablyOk,
             explanation: "nothing to check on subpath".to_string(),
         },
-        Some(s) => {
-            let mut findings = vec![];
+        Some(_s) => {
+            let findings = vec![];
             EvalResult::aggregate(&findings)
         }
     }
@@ -307,7 +467,9 @@ pub fn eval_purl_subpath(subpath: Option<String>) -> EvalResult {
 mod tests {
     use crate::purl_data::{PurlComponent, PurlNamespace, PurlType};
 
-    use super::{eval_purl_namespace, EvalResultLevel};
+    use super::{
+        eval_purl_namespace, EvalResult, EvalResultLevel, RegistryMetadata, VersionWarning,
+    };
 
     use paste::paste;
 
@@ -721,4 +883,33 @@ mod tests {
         EvalResultLevel::Invalid,
         true
     );
+
+    #[test]
+    fn test_adjust_version_result() {
+        let verified = EvalResult {
+            level: EvalResultLevel::Verified,
+            explanation: "exists on crates.io".to_string(),
+        };
+
+        let metadata = RegistryMetadata {
+            warnings: vec![VersionWarning::Prerelease],
+            ..Default::default()
+        };
+        assert_eq!(metadata.adjust_version_result(verified.clone()), verified);
+
+        let metadata = RegistryMetadata {
+            warnings: vec![
+                VersionWarning::Yanked,
+                VersionWarning::Deprecated(Some("use 2.x".to_string())),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            metadata.adjust_version_result(verified),
+            EvalResult {
+                level: EvalResultLevel::ProbablyOk,
+                explanation: "exists on crates.io, but yanked and deprecated: use 2.x".to_string(),
+            }
+        );
+    }
 }
@@ -0,0 +1,312 @@
+use serde_json::Value;
+
+use crate::purl_cyclonedx::{self, CyclonedxErr};
+use crate::purl_data::Purl;
+use crate::purl_sbom::SbomErr;
+use crate::purl_spdx::{self, SpdxErr};
+
+/// A purl the fix rewrote, along with the component it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PurlChange {
+    pub reference: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// An SBOM with its purls canonicalized, and the log of what had to change for that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedSbom {
+    pub content: String,
+    pub changes: Vec<PurlChange>,
+    /// The usual file extension for the document's format.
+    pub extension: &'static str,
+}
+
+/// The canonical form of the purl, if it parses and differs from it.
+pub fn fix_purl(purl: &str) -> Option<String> {
+    let fixed = Purl::parse(purl).ok()?.as_canonical().to_string();
+    Some(fixed).filter(|fixed| fixed != purl)
+}
+
+/// Canonicalizes every purl of a CycloneDX (JSON or XML) or SPDX (JSON, YAML or tag-value)
+/// document, leaving purls that do not parse as they are.
+///
+/// XML and tag-value documents are rewritten in place, JSON and YAML ones are written anew
+/// (keeping the order of their fields, but not their formatting or YAML comments).
+pub fn fix(content: &str) -> Result<FixedSbom, SbomErr> {
+    let mut changes = vec![];
+    let is_json = content.trim_start().starts_with('{');
+    let (content, extension) = if purl_cyclonedx::is_cyclonedx(content) && !is_json {
+        (fix_xml(content, &mut changes)?, "xml")
+    } else if purl_cyclonedx::is_cyclonedx(content) {
+        let fixed = fix_json(content, &mut changes)
+            .map_err(|e| CyclonedxErr::InvalidJson(e.to_string()))?;
+        (fixed, "json")
+    } else if purl_spdx::is_spdx(content) && is_json {
+        let fixed =
+            fix_json(content, &mut changes).map_err(|e| SpdxErr::InvalidJson(e.to_string()))?;
+        (fixed, "json")
+    } else if purl_spdx::is_spdx(content) && purl_spdx::is_tag_value(content) {
+        (fix_tag_value(content, &mut changes), "spdx")
+    } else if purl_spdx::is_spdx(content) {
+        let mut document: Value =
+            serde_yaml::from_str(content).map_err(|e| SpdxErr::InvalidYaml(e.to_string()))?;
+        fix_value(&mut document, "", &mut changes);
+        let fixed =
+            serde_yaml::to_string(&document).map_err(|e| SpdxErr::InvalidYaml(e.to_string()))?;
+        (fixed, "yaml")
+    } else {
+        return Err(SbomErr::UnknownFormat);
+    };
+    Ok(FixedSbom {
+        content,
+        changes,
+        extension,
+    })
+}
+
+fn fix_json(content: &str, changes: &mut Vec<PurlChange>) -> serde_json::Result<String> {
+    let mut document: Value = serde_json::from_str(content)?;
+    fix_value(&mut document, "", changes);
+    Ok(serde_json::to_string_pretty(&document)? + "\n")
+}
+
+/// Fixes the purls wherever the formats keep them: CycloneDX's `purl`, the SPDX 2.x external
+/// references of type `purl` and SPDX 3's `software_packageUrl` and `packageUrl` identifiers.
+fn fix_value(value: &mut Value, reference: &str, changes: &mut Vec<PurlChange>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                fix_value(value, reference, changes);
+            }
+        }
+        Value::Object(object) => {
+            let reference = ["bom-ref", "SPDXID", "spdxId", "name"]
+                .iter()
+                .find_map(|key| object.get(*key)?.as_str())
+                .unwrap_or(reference)
+                .to_string();
+            let is = |key: &str, expected: &str| {
+                object.get(key).and_then(Value::as_str) == Some(expected)
+            };
+            let purl_key = if is("referenceType", "purl") {
+                "referenceLocator"
+            } else if is("externalIdentifierType", "packageUrl") {
+                "identifier"
+            } else {
+                "purl"
+            };
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Value::String(purl) if key == purl_key || key == "software_packageUrl" => {
+                        if let Some(fixed) = fix_purl(purl) {
+                            changes.push(PurlChange {
+                                reference: reference.clone(),
+                                before: std::mem::replace(purl, fixed.clone()),
+                                after: fixed,
+                            });
+                        }
+                    }
+                    _ => fix_value(value, &reference, changes),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn fix_xml(content: &str, changes: &mut Vec<PurlChange>) -> Result<String, CyclonedxErr> {
+    let document =
+        roxmltree::Document::parse(content).map_err(|e| CyclonedxErr::InvalidXml(e.to_string()))?;
+
+    let mut replacements = vec![];
+    for purl in document
+        .descendants()
+        .filter(|node| node.is_element() && node.tag_name().name() == "purl")
+    {
+        let Some(text) = purl.first_child().filter(|child| child.is_text()) else {
+            continue;
+        };
+        let Some(fixed) = fix_purl(text.text().unwrap_or_default().trim()) else {
+            continue;
+        };
+        let component = purl.parent_element();
+        let reference = component
+            .and_then(|component| component.attribute("bom-ref"))
+            .or_else(|| {
+                component?
+                    .children()
+                    .find(|child| child.is_element() && child.tag_name().name() == "name")?
+                    .text()
+            })
+            .unwrap_or_default();
+        changes.push(PurlChange {
+            reference: reference.trim().to_string(),
+            before: text.text().unwrap_or_default().trim().to_string(),
+            after: fixed.clone(),
+        });
+
+        // the raw text may be escaped differently, only its surrounding whitespace is kept
+        let raw = &content[text.range()];
+        let escaped = fixed
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let leading = &raw[..raw.len() - raw.trim_start().len()];
+        let trailing = &raw[raw.trim_end().len()..];
+        replacements.push((text.range(), format!("{leading}{escaped}{trailing}")));
+    }
+
+    let mut fixed = content.to_string();
+    for (range, replacement) in replacements.into_iter().rev() {
+        fixed.replace_range(range, &replacement);
+    }
+    Ok(fixed)
+}
+
+fn fix_tag_value(content: &str, changes: &mut Vec<PurlChange>) -> String {
+    // like the reading, a package is referred to by the first SPDXID following its name
+    let mut reference = String::new();
+    let mut awaiting_id = false;
+    let mut fixed = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let (tag, value) = line.split_once(':').unwrap_or((line, ""));
+        match tag {
+            "PackageName" => {
+                reference = value.trim().to_string();
+                awaiting_id = true;
+            }
+            "SPDXID" if awaiting_id => {
+                reference = value.trim().to_string();
+                awaiting_id = false;
+            }
+            "ExternalRef" => {
+                let mut fields = value.split_whitespace().skip(1);
+                if let (Some("purl"), Some(locator)) = (fields.next(), fields.next()) {
+                    if let Some(purl) = fix_purl(locator) {
+                        changes.push(PurlChange {
+                            reference: reference.clone(),
+                            before: locator.to_string(),
+                            after: purl.clone(),
+                        });
+                        fixed.push_str(&line.replacen(locator, &purl, 1));
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        fixed.push_str(line);
+    }
+    fixed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fix, PurlChange};
+
+    fn change(reference: &str, before: &str, after: &str) -> PurlChange {
+        PurlChange {
+            reference: reference.to_string(),
+            before: before.to_string(),
+            after: after.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fix_json() {
+        let bom = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "components": [
+                { "bom-ref": "django", "name": "Django_Rest", "purl": "pkg:PyPI/Django_Rest@1.0" },
+                { "bom-ref": "left-pad", "name": "left-pad", "purl": "pkg:npm/left-pad@1.3.0" }
+            ]
+        }"#;
+        let fixed = fix(bom).unwrap();
+        assert_eq!(
+            fixed.changes,
+            vec![change(
+                "django",
+                "pkg:PyPI/Django_Rest@1.0",
+                "pkg:pypi/django-rest@1.0"
+            )]
+        );
+        // the order of the fields is kept
+        assert!(fixed.content.find("bomFormat") < fixed.content.find("components"));
+        assert!(fixed.content.contains("\"pkg:pypi/django-rest@1.0\""));
+        assert_eq!(fixed.extension, "json");
+    }
+
+    #[test]
+    fn test_fix_xml() {
+        let bom = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library" bom-ref="deb">
+      <name>curl</name>
+      <purl> pkg:deb/debian/curl@7.50.3-1?distro=jessie&amp;arch=i386 </purl>
+    </component>
+  </components>
+</bom>"#;
+        let fixed = fix(bom).unwrap();
+        assert_eq!(
+            fixed.changes,
+            vec![change(
+                "deb",
+                "pkg:deb/debian/curl@7.50.3-1?distro=jessie&arch=i386",
+                "pkg:deb/debian/curl@7.50.3-1?arch=i386&distro=jessie"
+            )]
+        );
+        assert_eq!(
+            fixed.content,
+            bom.replace("distro=jessie&amp;arch=i386", "arch=i386&amp;distro=jessie")
+        );
+    }
+
+    #[test]
+    fn test_fix_tag_value() {
+        let document = "SPDXVersion: SPDX-2.3
+SPDXID: SPDXRef-DOCUMENT
+
+PackageName: purl-spec
+SPDXID: SPDXRef-Package-purl-spec
+ExternalRef: PACKAGE-MANAGER purl pkg:github/Package-URL/purl-spec
+";
+        let fixed = fix(document).unwrap();
+        assert_eq!(
+            fixed.changes,
+            vec![change(
+                "SPDXRef-Package-purl-spec",
+                "pkg:github/Package-URL/purl-spec",
+                "pkg:github/package-url/purl-spec"
+            )]
+        );
+        assert_eq!(
+            fixed.content,
+            document.replace("Package-URL/purl-spec", "package-url/purl-spec")
+        );
+    }
+
+    #[test]
+    fn test_fix_yaml() {
+        let document = "spdxVersion: SPDX-2.3
+packages:
+- SPDXID: SPDXRef-Package-core
+  name: core
+  externalRefs:
+  - referenceCategory: PACKAGE-MANAGER
+    referenceType: purl
+    referenceLocator: pkg:npm/@angular/core@17.0.0
+";
+        let fixed = fix(document).unwrap();
+        assert_eq!(
+            fixed.changes,
+            vec![change(
+                "SPDXRef-Package-core",
+                "pkg:npm/@angular/core@17.0.0",
+                "pkg:npm/%40angular/core@17.0.0"
+            )]
+        );
+        assert_eq!(fixed.content, document.replace("@angular", "%40angular"));
+    }
+}
@@ -0,0 +1,826 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::purl_data::{Purl, PurlType};
+
+/// The lockfiles purls can be generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockfileFormat {
+    CargoLock,
+    PackageLock,
+    YarnLock,
+    PnpmLock,
+    PoetryLock,
+    Requirements,
+    GoSum,
+}
+
+impl LockfileFormat {
+    /// The format of a lockfile by its file name, e.g. `Cargo.lock` or `requirements-dev.txt`.
+    pub fn of_file_name(file_name: &str) -> Option<LockfileFormat> {
+        match file_name {
+            "Cargo.lock" => Some(LockfileFormat::CargoLock),
+            "package-lock.json" | "npm-shrinkwrap.json" => Some(LockfileFormat::PackageLock),
+            "yarn.lock" => Some(LockfileFormat::YarnLock),
+            "pnpm-lock.yaml" => Some(LockfileFormat::PnpmLock),
+            "poetry.lock" => Some(LockfileFormat::PoetryLock),
+            "go.sum" => Some(LockfileFormat::GoSum),
+            name if name.starts_with("requirements") && name.ends_with(".txt") => {
+                Some(LockfileFormat::Requirements)
+            }
+            _ => None,
+        }
+    }
+
+    /// Guesses the format from the content, for lockfiles that come without their file name.
+    pub fn sniff(content: &str) -> Option<LockfileFormat> {
+        if content.trim_start().starts_with('{') {
+            return content
+                .contains("\"lockfileVersion\"")
+                .then_some(LockfileFormat::PackageLock);
+        }
+        if content.contains("[[package]]") {
+            // poetry records which Python versions a package supports
+            return Some(if content.contains("python-versions") {
+                LockfileFormat::PoetryLock
+            } else {
+                LockfileFormat::CargoLock
+            });
+        }
+        if content
+            .lines()
+            .any(|line| line.starts_with("lockfileVersion:"))
+        {
+            return Some(LockfileFormat::PnpmLock);
+        }
+        if content.contains("# yarn lockfile v1")
+            || content.lines().any(|line| line.starts_with("__metadata:"))
+        {
+            return Some(LockfileFormat::YarnLock);
+        }
+
+        let lines = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            None
+        } else if lines.iter().all(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            fields.len() == 3 && fields[2].starts_with("h1:")
+        }) {
+            Some(LockfileFormat::GoSum)
+        } else if lines
+            .iter()
+            .all(|line| line.starts_with('-') || line.contains("==") || line.contains(" @ "))
+        {
+            Some(LockfileFormat::Requirements)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for LockfileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file_name = match self {
+            LockfileFormat::CargoLock => "Cargo.lock",
+            LockfileFormat::PackageLock => "package-lock.json",
+            LockfileFormat::YarnLock => "yarn.lock",
+            LockfileFormat::PnpmLock => "pnpm-lock.yaml",
+            LockfileFormat::PoetryLock => "poetry.lock",
+            LockfileFormat::Requirements => "requirements.txt",
+            LockfileFormat::GoSum => "go.sum",
+        };
+        write!(f, "{file_name}")
+    }
+}
+
+impl std::str::FromStr for LockfileFormat {
+    type Err = String;
+
+    /// Accepts the file names the lockfiles usually have.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LockfileFormat::of_file_name(s).ok_or_else(|| {
+            format!(
+                "unknown lockfile '{s}' (expected Cargo.lock, package-lock.json, yarn.lock, \
+                 pnpm-lock.yaml, poetry.lock, requirements.txt or go.sum)"
+            )
+        })
+    }
+}
+
+/// Reads the purls of the packages a lockfile pins, in the order the lockfile lists them.
+///
+/// Packages from git get a `vcs_url` qualifier pointing at the commit, those from a directory,
+/// a tarball or a URL other than the registry a `download_url` one.
+pub fn read(content: &str, format: LockfileFormat) -> Result<Vec<Purl>, LockfileErr> {
+    let purls = match format {
+        LockfileFormat::CargoLock => read_cargo_lock(content)?,
+        LockfileFormat::PackageLock => read_package_lock(content)?,
+        LockfileFormat::YarnLock => read_yarn_lock(content),
+        LockfileFormat::PnpmLock => read_pnpm_lock(content)?,
+        LockfileFormat::PoetryLock => read_poetry_lock(content)?,
+        LockfileFormat::Requirements => read_requirements(content),
+        LockfileFormat::GoSum => read_go_sum(content),
+    };
+
+    // e.g. npm installs the same package in several places
+    let mut seen = HashSet::new();
+    Ok(purls
+        .into_iter()
+        .map(|purl| purl.as_canonical())
+        .filter(|purl| seen.insert(purl.to_string()))
+        .collect())
+}
+
+/// Turns a git source into a `vcs_url`, with the commit (if any is given, or is in the fragment
+/// of the source) after an '@', e.g. `git+https://github.com/rust-lang/regex?branch=main#3c5a9e8`
+/// into `git+https://github.com/rust-lang/regex@3c5a9e8`.
+fn vcs_url(source: &str, commit: Option<&str>) -> String {
+    let (url, fragment) = source.split_once('#').unwrap_or((source, ""));
+    let url = url.split_once('?').map_or(url, |(url, _)| url);
+    let url = if url.starts_with("git+") || url.starts_with("git://") {
+        url.to_string()
+    } else {
+        format!("git+{url}")
+    };
+    let commit = commit.or(Some(fragment.trim_start_matches("commit=")).filter(|c| !c.is_empty()));
+    match commit {
+        Some(commit) => format!("{url}@{commit}"),
+        None => url,
+    }
+}
+
+/// Where an npm package came from if not from the registry: a git repository, or a tarball or
+/// directory otherwise.
+fn npm_source_qualifiers(source: &str) -> Vec<(&'static str, String)> {
+    const REGISTRIES: [&str; 2] = [
+        "https://registry.npmjs.org/",
+        "https://registry.yarnpkg.com/",
+    ];
+    if source.is_empty()
+        || REGISTRIES
+            .iter()
+            .any(|registry| source.starts_with(registry))
+        || source.starts_with("npm:")
+        || source.starts_with("patch:")
+    {
+        vec![]
+    } else if let Some(repo) = source.strip_prefix("github:") {
+        vec![(
+            "vcs_url",
+            vcs_url(&format!("https://github.com/{repo}"), None),
+        )]
+    } else if source.starts_with("git") || source.contains(".git#") || source.ends_with(".git") {
+        vec![("vcs_url", vcs_url(source, None))]
+    } else if let Some(path) = ["file:", "link:", "portal:"]
+        .iter()
+        .find_map(|protocol| source.strip_prefix(protocol))
+    {
+        // yarn appends the workspace the path is relative to, after a '::'
+        let path = path.split_once("::").map_or(path, |(path, _)| path);
+        vec![("download_url", format!("file:{path}"))]
+    } else {
+        vec![("download_url", source.to_string())]
+    }
+}
+
+fn read_cargo_lock(content: &str) -> Result<Vec<Purl>, LockfileErr> {
+    let lock: CargoLock =
+        toml::from_str(content).map_err(|e| LockfileErr::InvalidToml(e.to_string()))?;
+    Ok(lock
+        .package
+        .iter()
+        .filter_map(|package| {
+            // packages without a source are the workspace's own and path dependencies
+            let qualifiers = match package.source.as_deref()? {
+                "registry+https://github.com/rust-lang/crates.io-index"
+                | "sparse+https://index.crates.io/" => vec![],
+                source if source.starts_with("git+") => {
+                    vec![("vcs_url", vcs_url(source, None))]
+                }
+                // with the protocol, as it tells a git index from a sparse one
+                source => vec![("repository_url", source.to_string())],
+            };
+            Some(Purl::from_parts(
+                PurlType::Cargo,
+                &package.name,
+                Some(&package.version),
+                qualifiers,
+            ))
+        })
+        .collect())
+}
+
+fn read_package_lock(content: &str) -> Result<Vec<Purl>, LockfileErr> {
+    let lock: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| LockfileErr::InvalidJson(e.to_string()))?;
+    let string = |value: &serde_json::Value, key: &str| {
+        value
+            .get(key)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    };
+    let npm_purl = |name: &str, version: Option<String>, resolved: Option<String>| {
+        // lockfileVersion 1 has the source of git dependencies as their version
+        let (version, resolved) = match version {
+            Some(version) if version.contains(':') => (None, Some(version)),
+            version => (version, resolved),
+        };
+        Purl::from_parts(
+            PurlType::Npm,
+            name,
+            version.as_deref(),
+            npm_source_qualifiers(resolved.as_deref().unwrap_or_default()),
+        )
+    };
+
+    let mut purls = vec![];
+    if let Some(packages) = lock
+        .get("packages")
+        .and_then(|packages| packages.as_object())
+    {
+        for (path, package) in packages {
+            // the project itself, and the links to the workspaces and path dependencies, which are
+            // listed (and skipped) under their own path as well
+            if !path.contains("node_modules/")
+                || package.get("link").and_then(|link| link.as_bool()) == Some(true)
+            {
+                continue;
+            }
+            // packages are installed at e.g. node_modules/a/node_modules/@scope/b, aliased ones
+            // name the package they install
+            let name = string(package, "name").or_else(|| {
+                path.rsplit_once("node_modules/")
+                    .map(|(_, name)| name.to_string())
+            });
+            let Some(name) = name else {
+                continue;
+            };
+            purls.push(npm_purl(
+                &name,
+                string(package, "version"),
+                string(package, "resolved"),
+            ));
+        }
+    } else {
+        // lockfileVersion 1 nests the dependencies that could not be hoisted
+        fn walk(dependencies: &serde_json::Value, visit: &mut dyn FnMut(&str, &serde_json::Value)) {
+            for (name, dependency) in dependencies.as_object().into_iter().flatten() {
+                visit(name, dependency);
+                if let Some(nested) = dependency.get("dependencies") {
+                    walk(nested, visit);
+                }
+            }
+        }
+        if let Some(dependencies) = lock.get("dependencies") {
+            walk(dependencies, &mut |name, dependency| {
+                purls.push(npm_purl(
+                    name,
+                    string(dependency, "version"),
+                    string(dependency, "resolved"),
+                ))
+            });
+        }
+    }
+    Ok(purls)
+}
+
+/// Splits an npm package spec like `@scope/name@^1.0.0` into the name and what follows the '@'.
+fn split_npm_spec(spec: &str) -> (&str, &str) {
+    match spec.char_indices().skip(1).find(|(_, c)| *c == '@') {
+        Some((i, _)) => (&spec[..i], &spec[i + 1..]),
+        None => (spec, ""),
+    }
+}
+
+/// Reads both the classic format (`version "1.3.0"`, `resolved "<url>"`) and the one of yarn 2+
+/// (`version: 1.3.0`, `resolution: "left-pad@npm:1.3.0"`).
+fn read_yarn_lock(content: &str) -> Vec<Purl> {
+    struct Entry {
+        name: String,
+        version: Option<String>,
+        source: Option<String>,
+    }
+    fn entry_purl(entry: Entry) -> Option<Purl> {
+        let source = entry.source.unwrap_or_default();
+        // the project's own workspaces
+        if source.starts_with("workspace:") {
+            return None;
+        }
+        Some(Purl::from_parts(
+            PurlType::Npm,
+            &entry.name,
+            entry.version.as_deref(),
+            npm_source_qualifiers(&source),
+        ))
+    }
+
+    let mut purls = vec![];
+    let mut current: Option<Entry> = None;
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            purls.extend(current.take().and_then(entry_purl));
+            // e.g. `"@babel/core@^7.0.0", "@babel/core@^7.1.0":` listing the specs resolved alike
+            let spec = line
+                .trim_end_matches(':')
+                .split(", ")
+                .next()
+                .unwrap_or_default()
+                .trim_matches('"');
+            if spec != "__metadata" {
+                current = Some(Entry {
+                    name: split_npm_spec(spec).0.to_string(),
+                    version: None,
+                    source: None,
+                });
+            }
+        } else if let Some(entry) = current.as_mut().filter(|_| !line.starts_with("   ")) {
+            let line = line.trim();
+            let (key, value) = line.split_once([' ', ':']).unwrap_or((line, ""));
+            let value = value.trim_start_matches(':').trim().trim_matches('"');
+            match key {
+                "version" => entry.version = Some(value.to_string()),
+                "resolved" => entry.source = Some(value.to_string()),
+                "resolution" => entry.source = Some(split_npm_spec(value).1.to_string()),
+                _ => {}
+            }
+        }
+    }
+    purls.extend(current.and_then(entry_purl));
+    purls
+}
+
+fn read_pnpm_lock(content: &str) -> Result<Vec<Purl>, LockfileErr> {
+    let lock: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| LockfileErr::InvalidYaml(e.to_string()))?;
+    let string = |value: &serde_yaml::Value, key: &str| {
+        value
+            .get(key)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    };
+
+    // the numbers are strings since version 6
+    let is_v5 = match lock.get("lockfileVersion") {
+        Some(serde_yaml::Value::Number(version)) => version.as_f64().is_some_and(|v| v < 6.0),
+        _ => false,
+    };
+
+    let mut purls = vec![];
+    let packages = lock
+        .get("packages")
+        .and_then(|packages| packages.as_mapping());
+    for (key, package) in packages.into_iter().flatten() {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        // '/left-pad@1.3.0' (v6), 'left-pad@1.3.0' (v9) or '/left-pad/1.3.0' (v5), followed by
+        // the peer dependencies the package was resolved with
+        let key = key.trim_start_matches('/');
+        let key = key.split_once('(').map_or(key, |(key, _)| key);
+        let (name, version) = if is_v5 {
+            match key.rsplit_once('/') {
+                Some((name, version)) => (name, version.split('_').next().unwrap_or_default()),
+                None => continue,
+            }
+        } else {
+            split_npm_spec(key)
+        };
+        // packages from elsewhere than the registry have their name and version spelled out
+        let name = string(package, "name").unwrap_or_else(|| name.to_string());
+        let version = string(package, "version").unwrap_or_else(|| version.to_string());
+
+        let resolution = package.get("resolution");
+        let resolution = |key: &str| resolution.and_then(|resolution| string(resolution, key));
+        let qualifiers =
+            if let (Some(repo), Some(commit)) = (resolution("repo"), resolution("commit")) {
+                vec![("vcs_url", vcs_url(&repo, Some(&commit)))]
+            } else if let Some(directory) = resolution("directory") {
+                vec![("download_url", format!("file:{directory}"))]
+            } else {
+                npm_source_qualifiers(&resolution("tarball").unwrap_or_default())
+            };
+        purls.push(Purl::from_parts(
+            PurlType::Npm,
+            &name,
+            Some(&version),
+            qualifiers,
+        ));
+    }
+    Ok(purls)
+}
+
+fn read_poetry_lock(content: &str) -> Result<Vec<Purl>, LockfileErr> {
+    let lock: PoetryLock =
+        toml::from_str(content).map_err(|e| LockfileErr::InvalidToml(e.to_string()))?;
+    Ok(lock
+        .package
+        .iter()
+        .map(|package| {
+            let qualifiers = match &package.source {
+                None => vec![],
+                Some(source) => match source.typex.as_str() {
+                    "git" => {
+                        let commit = source
+                            .resolved_reference
+                            .as_deref()
+                            .or(source.reference.as_deref());
+                        vec![("vcs_url", vcs_url(&source.url, commit))]
+                    }
+                    "directory" | "file" => vec![("download_url", format!("file:{}", source.url))],
+                    "url" => vec![("download_url", source.url.clone())],
+                    // a package index other than PyPI
+                    _ => vec![("repository_url", source.url.clone())],
+                },
+            };
+            Purl::from_parts(
+                PurlType::Pypi,
+                &package.name,
+                Some(&package.version),
+                qualifiers,
+            )
+        })
+        .collect())
+}
+
+/// Reads the requirements that pin a version (`name==1.0`) or name a source (`name @ <url>`,
+/// `-e git+<url>#egg=name`), skipping the ones that only constrain the version.
+fn read_requirements(content: &str) -> Vec<Purl> {
+    fn without_extras(requirement: &str) -> &str {
+        requirement
+            .split_once('[')
+            .map_or(requirement, |(name, _)| name)
+            .trim()
+    }
+    fn source_qualifiers(source: &str) -> Vec<(&'static str, String)> {
+        let url = source.split_once('#').map_or(source, |(url, _)| url);
+        if ["git+", "hg+", "svn+", "bzr+"]
+            .iter()
+            .any(|vcs| url.starts_with(vcs))
+        {
+            vec![("vcs_url", url.to_string())]
+        } else if url.starts_with('.') || url.starts_with('/') {
+            vec![("download_url", format!("file:{url}"))]
+        } else {
+            vec![("download_url", url.to_string())]
+        }
+    }
+    let is_name = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+    };
+
+    let mut purls = vec![];
+    for line in content.lines() {
+        // comments need whitespace before the '#', which URLs have for their fragment
+        let line = line.split(" #").next().unwrap_or_default();
+        // options following the requirement, e.g. --hash
+        let line = line.split(" --").next().unwrap_or_default();
+        let line = line.trim().trim_end_matches('\\').trim();
+        let line = ["-e ", "--editable "]
+            .iter()
+            .find_map(|editable| line.strip_prefix(editable))
+            .unwrap_or(line)
+            .trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+            continue;
+        }
+        // environment markers
+        let line = line.split(" ;").next().unwrap_or_default();
+        let line = line.split("; ").next().unwrap_or_default().trim();
+
+        if let Some((name, source)) = line
+            .split_once('@')
+            .filter(|(name, _)| is_name(without_extras(name)))
+        {
+            purls.push(Purl::from_parts(
+                PurlType::Pypi,
+                without_extras(name),
+                None,
+                source_qualifiers(source.trim()),
+            ));
+        } else if line.contains(':') || line.starts_with('.') || line.starts_with('/') {
+            let egg = line
+                .split_once("#egg=")
+                .map(|(_, egg)| egg.split('&').next().unwrap_or_default());
+            if let Some(name) = egg.filter(|egg| is_name(egg)) {
+                purls.push(Purl::from_parts(
+                    PurlType::Pypi,
+                    name,
+                    None,
+                    source_qualifiers(line),
+                ));
+            }
+        } else {
+            let name_end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+                .unwrap_or(line.len());
+            let (name, rest) = line.split_at(name_end);
+            let rest = match rest.trim_start().strip_prefix('[') {
+                Some(rest) => rest.split_once(']').map_or("", |(_, rest)| rest),
+                None => rest,
+            };
+            let version = rest
+                .trim()
+                .strip_prefix("===")
+                .or_else(|| rest.trim().strip_prefix("=="))
+                .map(str::trim)
+                .filter(|version| !version.contains(['*', ',']));
+            if version.is_some() {
+                purls.push(Purl::from_parts(PurlType::Pypi, name, version, vec![]));
+            }
+        }
+    }
+    purls
+}
+
+fn read_go_sum(content: &str) -> Vec<Purl> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (module, version) = (fields.next()?, fields.next()?);
+            // modules only needed for their go.mod, to settle on versions, are not built with
+            (!version.ends_with("/go.mod"))
+                .then(|| Purl::from_parts(PurlType::Golang, module, Some(version), vec![]))
+        })
+        .collect()
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum LockfileErr {
+    #[error("not a valid TOML lockfile ({0})")]
+    InvalidToml(String),
+    #[error("not a valid JSON lockfile ({0})")]
+    InvalidJson(String),
+    #[error("not a valid YAML lockfile ({0})")]
+    InvalidYaml(String),
+}
+
+#[derive(serde::Deserialize)]
+pub struct CargoLock {
+    // intentionally abbreviated
+    // version = 3
+    //
+    // [[package]]
+    #[serde(default)]
+    pub package: Vec<CargoLockPackage>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct CargoLockPackage {
+    // intentionally abbreviated
+    // name = "regex"
+    pub name: String,
+    // version = "1.10.2"
+    pub version: String,
+    // source = "registry+https://github.com/rust-lang/crates.io-index"
+    pub source: Option<String>,
+    // checksum = "380b951a9c5e80ddfd6136919eef32310721aa4aacd4889a8d39124b026ab343"
+    // dependencies = [ "aho-corasick", "memchr", "regex-automata", "regex-syntax" ]
+}
+
+#[derive(serde::Deserialize)]
+pub struct PoetryLock {
+    // intentionally abbreviated
+    // [[package]]
+    #[serde(default)]
+    pub package: Vec<PoetryPackage>,
+    // [metadata]
+    // content-hash = "..."
+}
+
+#[derive(serde::Deserialize)]
+pub struct PoetryPackage {
+    // intentionally abbreviated
+    // name = "requests"
+    pub name: String,
+    // version = "2.31.0"
+    pub version: String,
+    // python-versions = ">=3.7"
+    //
+    // [package.source]
+    pub source: Option<PoetrySource>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct PoetrySource {
+    // type = "git"
+    #[serde(rename = "type")]
+    pub typex: String,
+    // url = "https://github.com/psf/requests.git"
+    pub url: String,
+    // reference = "main"
+    pub reference: Option<String>,
+    // resolved_reference = "0e322af87745eff34caffe4df68456ebc20d9068"
+    pub resolved_reference: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, LockfileFormat};
+
+    fn purls(content: &str) -> Vec<String> {
+        let format = LockfileFormat::sniff(content).unwrap();
+        read(content, format)
+            .unwrap()
+            .iter()
+            .map(|purl| purl.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_cargo_lock() {
+        let lock = r#"
+version = 3
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = ["regex"]
+
+[[package]]
+name = "regex"
+version = "1.10.2"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "leptos"
+version = "0.5.4"
+source = "git+https://github.com/leptos-rs/leptos?branch=main#3c5a9e8"
+
+[[package]]
+name = "my-crate"
+version = "0.3.1"
+source = "registry+https://crates.example.com/index.git"
+"#;
+        assert_eq!(
+            purls(lock),
+            vec![
+                "pkg:cargo/regex@1.10.2",
+                "pkg:cargo/leptos@0.5.4?vcs_url=git%2Bhttps:%2F%2Fgithub.com%2Fleptos-rs%2Fleptos%403c5a9e8",
+                "pkg:cargo/my-crate@0.3.1?repository_url=registry%2Bhttps:%2F%2Fcrates.example.com%2Findex.git",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_package_lock() {
+        let lock = r#"{
+            "name": "my-app",
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "name": "my-app", "version": "1.0.0" },
+                "node_modules/@angular/core": { "version": "17.0.0", "resolved": "https://registry.npmjs.org/@angular/core/-/core-17.0.0.tgz" },
+                "node_modules/a/node_modules/@angular/core": { "version": "17.0.0" },
+                "node_modules/local": { "resolved": "packages/local", "link": true },
+                "packages/local": { "name": "local", "version": "0.1.0" },
+                "packages/local/node_modules/left-pad": { "version": "1.3.0" },
+                "node_modules/forked": { "version": "1.0.0", "resolved": "git+ssh://git@github.com/me/forked.git#abc123" }
+            }
+        }"#;
+        assert_eq!(
+            purls(lock),
+            vec![
+                "pkg:npm/%40angular/core@17.0.0",
+                "pkg:npm/left-pad@1.3.0",
+                "pkg:npm/forked@1.0.0?vcs_url=git%2Bssh:%2F%2Fgit%40github.com%2Fme%2Fforked.git%40abc123",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_yarn_lock() {
+        let classic = r#"# yarn lockfile v1
+
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.10.4":
+  version "7.12.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.12.13.tgz#dcfc826b"
+  dependencies:
+    "@babel/highlight" "^7.12.13"
+
+local@file:../local:
+  version "0.1.0"
+  resolved "file:../local"
+"#;
+        assert_eq!(
+            purls(classic),
+            vec![
+                "pkg:npm/%40babel/code-frame@7.12.13",
+                "pkg:npm/local@0.1.0?download_url=file:..%2Flocal",
+            ]
+        );
+
+        let berry = r#"__metadata:
+  version: 6
+
+"left-pad@npm:^1.3.0":
+  version: 1.3.0
+  resolution: "left-pad@npm:1.3.0"
+
+"my-app@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "my-app@workspace:."
+"#;
+        assert_eq!(purls(berry), vec!["pkg:npm/left-pad@1.3.0"]);
+    }
+
+    #[test]
+    fn test_pnpm_lock() {
+        let lock = "lockfileVersion: '6.0'
+
+packages:
+
+  /@angular/core@17.0.0(rxjs@7.8.1):
+    resolution: {integrity: sha512-abc}
+
+  github.com/me/forked/abc123:
+    resolution: {tarball: https://codeload.github.com/me/forked/tar.gz/abc123}
+    name: forked
+    version: 1.0.0
+";
+        assert_eq!(
+            purls(lock),
+            vec![
+                "pkg:npm/%40angular/core@17.0.0",
+                "pkg:npm/forked@1.0.0?download_url=https:%2F%2Fcodeload.github.com%2Fme%2Fforked%2Ftar.gz%2Fabc123",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pnpm_lock_v5() {
+        let lock = "lockfileVersion: 5.4
+
+packages:
+
+  /@angular/core/17.0.0_rxjs@7.8.1:
+    resolution: {integrity: sha512-abc}
+";
+        assert_eq!(purls(lock), vec!["pkg:npm/%40angular/core@17.0.0"]);
+    }
+
+    #[test]
+    fn test_poetry_lock() {
+        let lock = r#"
+[[package]]
+name = "Django_Rest"
+version = "1.0"
+python-versions = ">=3.8"
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+python-versions = ">=3.7"
+
+[package.source]
+type = "git"
+url = "https://github.com/psf/requests.git"
+reference = "main"
+resolved_reference = "0e322af"
+"#;
+        assert_eq!(
+            purls(lock),
+            vec![
+                "pkg:pypi/django-rest@1.0",
+                "pkg:pypi/requests@2.31.0?vcs_url=git%2Bhttps:%2F%2Fgithub.com%2Fpsf%2Frequests.git%400e322af",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requirements() {
+        let requirements = "# pinned by pip-compile
+requests[security]==2.31.0 ; python_version >= \"3.7\" \\
+    --hash=sha256:abc
+-e git+https://github.com/me/forked.git@abc123#egg=forked
+local @ file:///srv/local
+--index-url https://example.com/simple
+";
+        assert_eq!(
+            purls(requirements),
+            vec![
+                "pkg:pypi/requests@2.31.0",
+                "pkg:pypi/forked?vcs_url=git%2Bhttps:%2F%2Fgithub.com%2Fme%2Fforked.git%40abc123",
+                "pkg:pypi/local?download_url=file:%2F%2F%2Fsrv%2Flocal",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_sum() {
+        let sum = "github.com/google/uuid v1.5.0 h1:1p67kYwdtXjb0gL0BPiP1Av9wiZPo5A8z2cWkTZ+eyU=
+github.com/google/uuid v1.5.0/go.mod h1:TIyPZe4MgqvfeYDBFedMoGGpEw/LqOeaOT+nhxU+yHo=
+golang.org/x/mod v0.14.0/go.mod h1:hTbmBsO62+eylJbnUtE2MGJUyE7QWk4xUqPFrRgJ+7c=
+";
+        assert_eq!(purls(sum), vec!["pkg:golang/github.com/google/uuid@v1.5.0"]);
+    }
+}
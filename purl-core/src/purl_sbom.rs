@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use crate::purl_batch::BatchEntry;
+use crate::purl_data::{Purl, PurlComponent, PurlNamespace, PurlType};
+use crate::{purl_cyclonedx, purl_spdx};
+
+/// A component of an SBOM, as far as its purls are concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SbomComponent {
+    /// How the document refers to the component, i.e. its `bom-ref` or SPDX ID.
+    pub reference: String,
+    pub group: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub purls: Vec<String>,
+}
+
+/// Whether the document is an SBOM in one of the formats that can be read.
+pub fn is_sbom(content: &str) -> bool {
+    purl_cyclonedx::is_cyclonedx(content) || purl_spdx::is_spdx(content)
+}
+
+/// Reads the components of an SBOM.
+pub fn read(content: &str) -> Result<Vec<SbomComponent>, SbomErr> {
+    if purl_cyclonedx::is_cyclonedx(content) {
+        Ok(purl_cyclonedx::read(content)?)
+    } else if purl_spdx::is_spdx(content) {
+        Ok(purl_spdx::read(content)?)
+    } else {
+        Err(SbomErr::UnknownFormat)
+    }
+}
+
+/// Evaluates the purls of the components, and suggests purls for the components without one.
+pub fn entries(components: &[SbomComponent]) -> Vec<BatchEntry> {
+    let hint = ecosystem_hint(components);
+    let mut entries = vec![];
+    for component in components {
+        if component.purls.is_empty() {
+            let warnings = suggest_purl(component, hint.as_ref())
+                .map(|suggestion| vec![format!("suggested purl: {suggestion}")])
+                .unwrap_or_default();
+            entries.push(BatchEntry {
+                input: String::new(),
+                reference: Some(component.reference.clone()),
+                purl: Err("the component has no purl".to_string()),
+                results: None,
+                warnings,
+            });
+        }
+        for purl in &component.purls {
+            let mut entry = BatchEntry::evaluate(purl);
+            entry.reference = Some(component.reference.clone());
+            if let Ok(purl) = &entry.purl {
+                entry.warnings = mismatches(component, purl);
+            }
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// Where the purl disagrees with the name and version the document gives the component.
+pub fn mismatches(component: &SbomComponent, purl: &Purl) -> Vec<String> {
+    let decode = |s: &str| {
+        urlencoding::decode(s)
+            .map(|decoded| decoded.into_owned())
+            .unwrap_or_else(|_| s.to_string())
+    };
+    let purl_name = decode(&purl.name);
+    let mut mismatches = vec![];
+
+    if let Some(name) = component.name.as_deref().filter(|name| !name.is_empty()) {
+        // documents differ in whether they name packages with their namespace, and how they join
+        // it to the name (e.g. 'org.apache.commons:commons-lang3' or '@angular/core')
+        let namespace = purl.namespace.join("/");
+        let matches = |candidate: &str| {
+            candidate.eq_ignore_ascii_case(name)
+                || candidate
+                    .replace('_', "-")
+                    .eq_ignore_ascii_case(&name.replace('_', "-"))
+        };
+        let names = [
+            purl_name.clone(),
+            format!("{namespace}/{purl_name}"),
+            format!("{namespace}:{purl_name}"),
+        ];
+        if !names.iter().any(|candidate| matches(candidate)) {
+            mismatches.push(format!(
+                "the purl's name '{purl_name}' does not match the name '{name}'"
+            ));
+        }
+    }
+
+    if let (Some(version), Some(purl_version)) = (
+        component
+            .version
+            .as_deref()
+            .filter(|version| !version.is_empty()),
+        purl.version.as_deref().map(decode),
+    ) {
+        if version.trim_start_matches('v') != purl_version.trim_start_matches('v') {
+            mismatches.push(format!(
+                "the purl's version '{purl_version}' does not match the version '{version}'"
+            ));
+        }
+    }
+    mismatches
+}
+
+/// The type most of the document's purls have, which the components without a purl likely
+/// share.
+fn ecosystem_hint(components: &[SbomComponent]) -> Option<PurlType> {
+    let mut counts = HashMap::<String, usize>::new();
+    for purl in components
+        .iter()
+        .flat_map(|component| &component.purls)
+        .filter_map(|purl| Purl::parse(purl).ok())
+    {
+        *counts.entry(purl.typex.to_string()).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|(a_type, a_count), (b_type, b_count)| {
+            a_count.cmp(b_count).then_with(|| b_type.cmp(a_type))
+        })
+        .map(|(typex, _)| PurlType::new(&typex))
+}
+
+/// Builds a purl from the component's group, name and version, with scoped packages being npm
+/// ones and otherwise the type of the rest of the document.
+pub fn suggest_purl(component: &SbomComponent, hint: Option<&PurlType>) -> Option<Purl> {
+    let name = component.name.as_deref().filter(|name| !name.is_empty())?;
+    let group = component.group.as_deref().unwrap_or_default();
+    let typex = if group.starts_with('@') {
+        PurlType::Npm
+    } else {
+        hint?.clone()
+    };
+    Some(Purl {
+        typex,
+        namespace: PurlNamespace::new_naive(group),
+        name: urlencoding::encode(name).into_owned(),
+        version: component
+            .version
+            .as_deref()
+            .map(|version| urlencoding::encode(version).into_owned()),
+        qualifiers: None,
+        subpath: None,
+    })
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum SbomErr {
+    #[error(
+        "not an SBOM in a supported format (CycloneDX JSON or XML, SPDX JSON, YAML or tag-value)"
+    )]
+    UnknownFormat,
+    #[error(transparent)]
+    Cyclonedx(#[from] purl_cyclonedx::CyclonedxErr),
+    #[error(transparent)]
+    Spdx(#[from] purl_spdx::SpdxErr),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{entries, mismatches, SbomComponent};
+    use crate::purl_data::Purl;
+
+    fn component(reference: &str, group: &str, name: &str, purls: &[&str]) -> SbomComponent {
+        SbomComponent {
+            reference: reference.to_string(),
+            group: Some(group.to_string()).filter(|group| !group.is_empty()),
+            name: Some(name.to_string()),
+            version: Some("1.0.0".to_string()),
+            purls: purls.iter().map(|purl| purl.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_suggestions_follow_the_document() {
+        let components = [
+            component("a", "org.example", "a", &["pkg:maven/org.example/a@1.0.0"]),
+            component("b", "org.example", "b", &[]),
+            component("c", "@scope", "c", &[]),
+        ];
+        let entries = entries(&components);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].reference.as_deref(), Some("a"));
+        assert!(entries[0].purl.is_ok());
+        assert_eq!(
+            entries[1].warnings,
+            vec!["suggested purl: pkg:maven/org.example/b@1.0.0".to_string()]
+        );
+        assert_eq!(
+            entries[2].warnings,
+            vec!["suggested purl: pkg:npm/%40scope/c@1.0.0".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let mismatches_of = |name: &str, version: &str, purl: &str| {
+            let component = SbomComponent {
+                name: Some(name.to_string()),
+                version: Some(version.to_string()),
+                ..component("x", "", "", &[])
+            };
+            mismatches(&component, &Purl::parse(purl).unwrap()).len()
+        };
+        assert_eq!(
+            mismatches_of("left-pad", "1.3.0", "pkg:npm/left-pad@1.3.0"),
+            0
+        );
+        assert_eq!(
+            mismatches_of("@angular/core", "v17.0.0", "pkg:npm/%40angular/core@17.0.0"),
+            0
+        );
+        assert_eq!(
+            mismatches_of(
+                "org.apache.commons:commons-lang3",
+                "3.14.0",
+                "pkg:maven/org.apache.commons/commons-lang3@3.14.0"
+            ),
+            0
+        );
+        assert_eq!(
+            mismatches_of("Django_Rest", "1.0", "pkg:pypi/django-rest@1.0"),
+            0
+        );
+        assert_eq!(
+            mismatches_of("left-pad", "1.3.0", "pkg:npm/right-pad@1.3.0"),
+            1
+        );
+        assert_eq!(
+            mismatches_of("left-pad", "1.3.0", "pkg:npm/right-pad@1.2.0"),
+            2
+        );
+    }
+}
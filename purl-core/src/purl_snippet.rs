@@ -0,0 +1,277 @@
+use crate::purl_data::{self, Purl, PurlComponent, PurlQualifiers, PurlType};
+
+/// A piece of code for using a package with its ecosystem's own tooling, e.g. a line for the
+/// Cargo.toml or the `npm install` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub label: &'static str,
+    pub code: String,
+}
+
+impl Snippet {
+    fn new(label: &'static str, code: String) -> Snippet {
+        Snippet { label, code }
+    }
+}
+
+/// The snippets for installing or depending on the package the purl refers to, none for the
+/// types that have no (supported) tooling.
+///
+/// Qualifiers are carried over where the tooling has a counterpart for them (e.g. `classifier`
+/// and `type` for Maven, `repository_url` for registries other than the default one).
+pub fn snippets(purl: &Purl) -> Vec<Snippet> {
+    let decode = |s: &str| {
+        urlencoding::decode(s)
+            .map(|decoded| decoded.into_owned())
+            .unwrap_or_else(|_| s.to_string())
+    };
+    let namespace = purl.namespace.as_canonical().join("/");
+    let name = decode(&purl.name);
+    let full_name = if namespace.is_empty() {
+        name.clone()
+    } else {
+        format!("{namespace}/{name}")
+    };
+    let version = purl.version.as_deref().map(decode);
+    let qualifiers = PurlQualifiers::new_naive(purl.qualifiers.as_deref().unwrap_or_default());
+    let qualifier = |key: &str| purl_data::qualifier_value(&qualifiers, key);
+
+    match purl.typex {
+        PurlType::Cargo => {
+            let requirement = version.as_deref().unwrap_or("*");
+            let mut cargo_add = match &version {
+                Some(version) => format!("cargo add {name}@{version}"),
+                None => format!("cargo add {name}"),
+            };
+            // Cargo takes plain URLs for git indexes, and wants sparse ones (which an index
+            // without a protocol is taken for) prefixed
+            let dependency = match qualifier("repository_url") {
+                Some(repository_url) => {
+                    let index_url = match repository_url.strip_prefix("registry+") {
+                        Some(git_index) => purl_data::repository_url_as_base_url(git_index),
+                        None => format!(
+                            "sparse+{}",
+                            purl_data::repository_url_as_base_url(
+                                repository_url
+                                    .strip_prefix("sparse+")
+                                    .unwrap_or(repository_url)
+                            )
+                        ),
+                    };
+                    cargo_add += &format!(" --index {index_url}");
+                    format!(
+                        "{name} = {{ version = \"{requirement}\", registry-index = \"{index_url}\" }}"
+                    )
+                }
+                None => format!("{name} = \"{requirement}\""),
+            };
+            vec![
+                Snippet::new("Cargo.toml", dependency),
+                Snippet::new("cargo", cargo_add),
+            ]
+        }
+        PurlType::Npm => {
+            let mut install = match &version {
+                Some(version) => format!("npm install {full_name}@{version}"),
+                None => format!("npm install {full_name}"),
+            };
+            if let Some(repository_url) = qualifier("repository_url") {
+                install += &format!(
+                    " --registry {}",
+                    purl_data::repository_url_as_base_url(repository_url)
+                );
+            }
+            vec![Snippet::new("npm", install)]
+        }
+        PurlType::Maven => {
+            let mut dependency = format!(
+                "<dependency>\n  <groupId>{}</groupId>\n  <artifactId>{}</artifactId>\n",
+                xml_escape(&namespace.replace('/', ".")),
+                xml_escape(&name)
+            );
+            if let Some(version) = &version {
+                dependency += &format!("  <version>{}</version>\n", xml_escape(version));
+            }
+            for (qualifier_key, element) in [("classifier", "classifier"), ("type", "type")] {
+                if let Some(value) = qualifier(qualifier_key) {
+                    dependency += &format!("  <{element}>{}</{element}>\n", xml_escape(value));
+                }
+            }
+            dependency += "</dependency>";
+            let mut snippets = vec![Snippet::new("pom.xml", dependency)];
+            if let Some(repository_url) = qualifier("repository_url") {
+                let base_url = purl_data::repository_url_as_base_url(repository_url);
+                snippets.push(Snippet::new(
+                    "pom.xml repository",
+                    format!(
+                        "<repository>\n  <id>{id}</id>\n  <url>{url}</url>\n</repository>",
+                        id = xml_escape(base_url.split("://").nth(1).unwrap_or(&base_url)),
+                        url = xml_escape(&base_url)
+                    ),
+                ));
+            }
+            snippets
+        }
+        PurlType::Pypi => {
+            let mut install = match &version {
+                Some(version) => format!("pip install {name}=={version}"),
+                None => format!("pip install {name}"),
+            };
+            if let Some(repository_url) = qualifier("repository_url") {
+                install += &format!(
+                    " --index-url {}",
+                    purl_data::repository_url_as_base_url(repository_url)
+                );
+            }
+            vec![Snippet::new("pip", install)]
+        }
+        PurlType::Docker => {
+            // docker hub's official images live in 'library', which the reference leaves out
+            let mut reference = match (qualifier("repository_url"), namespace.as_str()) {
+                (Some(registry), _) => format!(
+                    "{}/{full_name}",
+                    registry
+                        .trim_end_matches('/')
+                        .split("://")
+                        .last()
+                        .unwrap_or(registry)
+                ),
+                (None, "library") => name.clone(),
+                (None, _) => full_name.clone(),
+            };
+            match &version {
+                Some(digest) if digest.starts_with("sha256:") => {
+                    if let Some(tag) = qualifier("tag") {
+                        reference += &format!(":{tag}");
+                    }
+                    reference += &format!("@{digest}");
+                }
+                Some(tag) => reference += &format!(":{tag}"),
+                None => {}
+            }
+            vec![Snippet::new("docker", format!("docker pull {reference}"))]
+        }
+        PurlType::Golang => {
+            let get = match &version {
+                Some(version) => format!("go get {full_name}@{version}"),
+                None => format!("go get {full_name}"),
+            };
+            vec![Snippet::new("go", get)]
+        }
+        PurlType::Nuget => {
+            let (reference, mut add) = match &version {
+                Some(version) => (
+                    format!(
+                        "<PackageReference Include=\"{}\" Version=\"{}\" />",
+                        xml_escape(&name),
+                        xml_escape(version)
+                    ),
+                    format!("dotnet add package {name} --version {version}"),
+                ),
+                None => (
+                    format!("<PackageReference Include=\"{}\" />", xml_escape(&name)),
+                    format!("dotnet add package {name}"),
+                ),
+            };
+            if let Some(repository_url) = qualifier("repository_url") {
+                add += &format!(
+                    " --source {}",
+                    purl_data::repository_url_as_base_url(repository_url)
+                );
+            }
+            vec![
+                Snippet::new(".csproj", reference),
+                Snippet::new("dotnet", add),
+            ]
+        }
+        _ => vec![],
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snippets, Snippet};
+    use crate::purl_data::Purl;
+
+    fn codes(purl: &str) -> Vec<String> {
+        snippets(&Purl::parse(purl).unwrap())
+            .into_iter()
+            .map(|Snippet { code, .. }| code)
+            .collect()
+    }
+
+    #[test]
+    fn test_snippets_cargo() {
+        assert_eq!(
+            codes("pkg:cargo/serde@1.0.195"),
+            vec!["serde = \"1.0.195\"", "cargo add serde@1.0.195"]
+        );
+        assert_eq!(
+            codes("pkg:cargo/my-crate@0.3.1?repository_url=crates.example.com/index/"),
+            vec![
+                "my-crate = { version = \"0.3.1\", registry-index = \"sparse+https://crates.example.com/index\" }",
+                "cargo add my-crate@0.3.1 --index sparse+https://crates.example.com/index"
+            ]
+        );
+        assert_eq!(
+            codes("pkg:cargo/my-crate@0.3.1?repository_url=registry%2Bhttps:%2F%2Fcrates.example.com%2Findex.git"),
+            vec![
+                "my-crate = { version = \"0.3.1\", registry-index = \"https://crates.example.com/index.git\" }",
+                "cargo add my-crate@0.3.1 --index https://crates.example.com/index.git"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snippets_npm() {
+        assert_eq!(
+            codes("pkg:npm/%40angular/core@17.0.0?repository_url=npm.example.com"),
+            vec!["npm install @angular/core@17.0.0 --registry https://npm.example.com"]
+        );
+    }
+
+    #[test]
+    fn test_snippets_maven() {
+        assert_eq!(
+            codes("pkg:maven/org.lwjgl/lwjgl@3.3.3?classifier=natives-linux"),
+            vec![
+                "<dependency>
+  <groupId>org.lwjgl</groupId>
+  <artifactId>lwjgl</artifactId>
+  <version>3.3.3</version>
+  <classifier>natives-linux</classifier>
+</dependency>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snippets_docker() {
+        assert_eq!(
+            codes("pkg:docker/library/nginx@sha256%3A4c0fdaa8b634?tag=1.25"),
+            vec!["docker pull nginx:1.25@sha256:4c0fdaa8b634"]
+        );
+        assert_eq!(
+            codes("pkg:docker/distroless/static@nonroot?repository_url=gcr.io"),
+            vec!["docker pull gcr.io/distroless/static:nonroot"]
+        );
+    }
+
+    #[test]
+    fn test_snippets_nuget() {
+        assert_eq!(
+            codes("pkg:nuget/Newtonsoft.Json@13.0.3"),
+            vec![
+                "<PackageReference Include=\"Newtonsoft.Json\" Version=\"13.0.3\" />",
+                "dotnet add package Newtonsoft.Json --version 13.0.3"
+            ]
+        );
+    }
+}
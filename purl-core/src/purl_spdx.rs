@@ -0,0 +1,295 @@
+use crate::purl_sbom::SbomComponent;
+
+/// Whether the document looks like an SPDX document, in any of the formats that can be read.
+pub fn is_spdx(content: &str) -> bool {
+    let content = content.trim_start();
+    if content.starts_with('{') {
+        content.contains("\"spdxVersion\"") || content.contains("spdx.org/rdf/3")
+    } else {
+        content
+            .lines()
+            .any(|line| line.starts_with("SPDXVersion:") || line.starts_with("spdxVersion:"))
+    }
+}
+
+/// Reads the packages of an SPDX 2.x document (JSON, YAML or tag-value) or the software
+/// packages of an SPDX 3 document (JSON-LD).
+pub fn read(content: &str) -> Result<Vec<SbomComponent>, SpdxErr> {
+    let trimmed = content.trim_start();
+    let packages = if trimmed.starts_with('{') {
+        let document: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| SpdxErr::InvalidJson(e.to_string()))?;
+        if document.get("@graph").is_some() {
+            return Ok(read_spdx3(&document));
+        }
+        serde_json::from_value::<SpdxDocument>(document)
+            .map_err(|e| SpdxErr::InvalidJson(e.to_string()))?
+            .packages
+    } else if is_tag_value(content) {
+        read_tag_value(content)
+    } else {
+        serde_yaml::from_str::<SpdxDocument>(content)
+            .map_err(|e| SpdxErr::InvalidYaml(e.to_string()))?
+            .packages
+    };
+
+    Ok(packages
+        .into_iter()
+        .map(|package| SbomComponent {
+            reference: package.spdx_id,
+            group: None,
+            name: Some(package.name),
+            version: package.version_info,
+            purls: package
+                .external_refs
+                .into_iter()
+                .filter(|external_ref| external_ref.reference_type == "purl")
+                .map(|external_ref| external_ref.reference_locator)
+                .collect(),
+        })
+        .collect())
+}
+
+/// Whether the document is in the tag-value format, rather than YAML (which spells the tags in
+/// camel case).
+pub fn is_tag_value(content: &str) -> bool {
+    content.lines().any(|line| line.starts_with("SPDXVersion:"))
+}
+
+/// Reads the tag-value format, where each package starts with its `PackageName`.
+fn read_tag_value(content: &str) -> Vec<SpdxPackage> {
+    let mut packages: Vec<SpdxPackage> = vec![];
+    for line in content.lines() {
+        let Some((tag, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        if tag == "PackageName" {
+            packages.push(SpdxPackage {
+                spdx_id: String::new(),
+                name: value,
+                version_info: None,
+                external_refs: vec![],
+            });
+            continue;
+        }
+        let Some(package) = packages.last_mut() else {
+            continue;
+        };
+        match tag {
+            // files listed after a package come with SPDXIDs of their own
+            "SPDXID" if package.spdx_id.is_empty() => package.spdx_id = value,
+            "PackageVersion" => package.version_info = Some(value),
+            "ExternalRef" => {
+                let mut fields = value.split_whitespace();
+                if let (Some(category), Some(reference_type), Some(locator)) =
+                    (fields.next(), fields.next(), fields.next())
+                {
+                    package.external_refs.push(SpdxExternalRef {
+                        reference_category: category.to_string(),
+                        reference_type: reference_type.to_string(),
+                        reference_locator: locator.to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    packages
+}
+
+/// Reads the software packages from the graph of an SPDX 3 document, which carry their purl in
+/// `software_packageUrl` or as an external identifier.
+fn read_spdx3(document: &serde_json::Value) -> Vec<SbomComponent> {
+    let string = |element: &serde_json::Value, key: &str| {
+        element
+            .get(key)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    };
+    document
+        .get("@graph")
+        .and_then(|graph| graph.as_array())
+        .into_iter()
+        .flatten()
+        .filter(|element| string(element, "type").is_some_and(|typex| typex == "software_Package"))
+        .map(|element| {
+            let identifiers = element
+                .get("externalIdentifier")
+                .and_then(|identifiers| identifiers.as_array())
+                .into_iter()
+                .flatten()
+                .filter(|identifier| {
+                    string(identifier, "externalIdentifierType")
+                        .is_some_and(|typex| typex == "packageUrl")
+                })
+                .filter_map(|identifier| string(identifier, "identifier"));
+            let mut purls = string(element, "software_packageUrl")
+                .into_iter()
+                .collect::<Vec<_>>();
+            for purl in identifiers {
+                if !purls.contains(&purl) {
+                    purls.push(purl);
+                }
+            }
+            SbomComponent {
+                reference: string(element, "spdxId").unwrap_or_default(),
+                group: None,
+                name: string(element, "name"),
+                version: string(element, "software_packageVersion"),
+                purls,
+            }
+        })
+        .collect()
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum SpdxErr {
+    #[error("not a valid SPDX JSON document ({0})")]
+    InvalidJson(String),
+    #[error("not a valid SPDX YAML document ({0})")]
+    InvalidYaml(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SpdxDocument {
+    // intentionally abbreviated
+    // {
+    //   "spdxVersion": "SPDX-2.3",
+    //   "SPDXID": "SPDXRef-DOCUMENT",
+    //   "packages": [ { "SPDXID": "SPDXRef-Package-left-pad", "name": "left-pad", ... }, ... ],
+    #[serde(default)]
+    pub packages: Vec<SpdxPackage>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SpdxPackage {
+    // intentionally abbreviated
+    // {
+    //   "SPDXID": "SPDXRef-Package-left-pad",
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    //   "name": "left-pad",
+    pub name: String,
+    //   "versionInfo": "1.3.0",
+    #[serde(rename = "versionInfo")]
+    pub version_info: Option<String>,
+    //   "externalRefs": [ { "referenceCategory": "PACKAGE-MANAGER", "referenceType": "purl", ... } ],
+    #[serde(rename = "externalRefs", default)]
+    pub external_refs: Vec<SpdxExternalRef>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SpdxExternalRef {
+    // {
+    //   "referenceCategory": "PACKAGE-MANAGER",
+    #[serde(rename = "referenceCategory")]
+    pub reference_category: String,
+    //   "referenceType": "purl",
+    #[serde(rename = "referenceType")]
+    pub reference_type: String,
+    //   "referenceLocator": "pkg:npm/left-pad@1.3.0"
+    #[serde(rename = "referenceLocator")]
+    pub reference_locator: String,
+    // }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    fn references_and_purls(content: &str) -> Vec<(String, Vec<String>)> {
+        read(content)
+            .unwrap()
+            .into_iter()
+            .map(|component| (component.reference, component.purls))
+            .collect()
+    }
+
+    fn expected() -> Vec<(String, Vec<String>)> {
+        vec![
+            (
+                "SPDXRef-Package-left-pad".to_string(),
+                vec!["pkg:npm/left-pad@1.3.0".to_string()],
+            ),
+            ("SPDXRef-Package-other".to_string(), vec![]),
+        ]
+    }
+
+    #[test]
+    fn test_read_json() {
+        let document = r#"{
+            "spdxVersion": "SPDX-2.3",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "packages": [
+                {
+                    "SPDXID": "SPDXRef-Package-left-pad",
+                    "name": "left-pad",
+                    "versionInfo": "1.3.0",
+                    "externalRefs": [
+                        { "referenceCategory": "SECURITY", "referenceType": "cpe23Type", "referenceLocator": "cpe:2.3:a:left-pad:left-pad:1.3.0:*:*:*:*:*:*:*" },
+                        { "referenceCategory": "PACKAGE-MANAGER", "referenceType": "purl", "referenceLocator": "pkg:npm/left-pad@1.3.0" }
+                    ]
+                },
+                { "SPDXID": "SPDXRef-Package-other", "name": "other" }
+            ]
+        }"#;
+        assert_eq!(references_and_purls(document), expected());
+    }
+
+    #[test]
+    fn test_read_yaml() {
+        let document = "
+spdxVersion: SPDX-2.3
+SPDXID: SPDXRef-DOCUMENT
+packages:
+  - SPDXID: SPDXRef-Package-left-pad
+    name: left-pad
+    versionInfo: 1.3.0
+    externalRefs:
+      - referenceCategory: PACKAGE_MANAGER
+        referenceType: purl
+        referenceLocator: pkg:npm/left-pad@1.3.0
+  - SPDXID: SPDXRef-Package-other
+    name: other
+";
+        assert_eq!(references_and_purls(document), expected());
+    }
+
+    #[test]
+    fn test_read_tag_value() {
+        let document = "SPDXVersion: SPDX-2.3
+SPDXID: SPDXRef-DOCUMENT
+
+PackageName: left-pad
+SPDXID: SPDXRef-Package-left-pad
+PackageVersion: 1.3.0
+ExternalRef: PACKAGE-MANAGER purl pkg:npm/left-pad@1.3.0
+
+PackageName: other
+SPDXID: SPDXRef-Package-other
+";
+        assert_eq!(references_and_purls(document), expected());
+    }
+
+    #[test]
+    fn test_read_spdx3() {
+        let document = r#"{
+            "@context": "https://spdx.org/rdf/3.0.1/spdx-context.jsonld",
+            "@graph": [
+                { "type": "CreationInfo", "@id": "_:creationinfo" },
+                {
+                    "type": "software_Package",
+                    "spdxId": "SPDXRef-Package-left-pad",
+                    "name": "left-pad",
+                    "software_packageVersion": "1.3.0",
+                    "software_packageUrl": "pkg:npm/left-pad@1.3.0"
+                },
+                { "type": "software_Package", "spdxId": "SPDXRef-Package-other", "name": "other" }
+            ]
+        }"#;
+        assert_eq!(references_and_purls(document), expected());
+    }
+}
@@ -0,0 +1,122 @@
+/// How many suggestions are offered at most.
+pub const MAX_SUGGESTIONS: usize = 8;
+
+/// A package (name) a registry search turned up for a name that was not found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameSuggestion {
+    /// Only set for types where the namespace would have to be replaced along with the name,
+    /// e.g. the owner of a GitHub repository.
+    pub namespace: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    /// The edit distance to the name that was searched for.
+    pub distance: usize,
+}
+
+impl NameSuggestion {
+    pub fn new(namespace: Option<String>, name: String, description: Option<String>) -> Self {
+        NameSuggestion {
+            namespace,
+            name,
+            description,
+            distance: 0,
+        }
+    }
+}
+
+/// The Levenshtein distance between two names, ignoring case and treating `-` and `_` alike (as
+/// registries such as crates.io and PyPI do).
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let normalize = |s: &str| -> Vec<char> {
+        s.chars()
+            .map(|c| if c == '_' { '-' } else { c })
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != b_char);
+            let insertion = row[j] + 1;
+            let deletion = previous_row[j + 1] + 1;
+            row.push(substitution.min(insertion).min(deletion));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+/// Scores the search results by their edit distance to the searched name and returns the closest
+/// ones first, leaving out duplicates and the searched name itself.
+pub fn rank(searched_name: &str, results: Vec<NameSuggestion>) -> Vec<NameSuggestion> {
+    let mut suggestions = results
+        .into_iter()
+        .filter(|result| result.name != searched_name)
+        .map(|result| NameSuggestion {
+            distance: edit_distance(searched_name, &result.name),
+            ..result
+        })
+        .collect::<Vec<_>>();
+    // stable, so for equal distances the registry's own relevance order is kept
+    suggestions.sort_by_key(|suggestion| suggestion.distance);
+    let mut seen = std::collections::HashSet::new();
+    suggestions
+        .retain(|suggestion| seen.insert((suggestion.namespace.clone(), suggestion.name.clone())));
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, rank, NameSuggestion};
+
+    use paste::paste;
+
+    macro_rules! test_distance {
+        ($name:ident, $l:expr, $r:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_edit_distance_ $name>]() {
+                let (l, r) = ($l, $r);
+                let expected = $expect;
+                let result = edit_distance(l, r);
+                if result != expected {
+                    panic!("edit_distance({l}, {r}) expects {expected} got {result}")
+                }
+            }
+            }
+        };
+    }
+
+    test_distance!(equal, "serde", "serde", 0);
+    test_distance!(case, "Serde", "serde", 0);
+    test_distance!(separator, "serde_json", "serde-json", 0);
+    test_distance!(typo, "sedre", "serde", 2);
+    test_distance!(missing_char, "expres", "express", 1);
+    test_distance!(empty, "", "tokio", 5);
+
+    #[test]
+    fn test_rank() {
+        let suggestion = |name: &str| NameSuggestion::new(None, name.to_string(), None);
+        let ranked = rank(
+            "expres",
+            vec![
+                suggestion("express-session"),
+                suggestion("expres"),
+                suggestion("express"),
+                suggestion("xpress"),
+                suggestion("express"),
+            ],
+        );
+        assert_eq!(
+            ranked
+                .iter()
+                .map(|suggestion| (suggestion.name.as_str(), suggestion.distance))
+                .collect::<Vec<_>>(),
+            vec![("express", 1), ("xpress", 2), ("express-session", 9)]
+        );
+    }
+}
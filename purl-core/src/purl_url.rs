@@ -0,0 +1,299 @@
+use crate::purl_data::{self, Purl, PurlComponent, PurlQualifiers, PurlType};
+
+/// Where to find the package a purl refers to: the page about it for people, and the artifact
+/// itself. Either is missing when the type has no such page (or one that cannot be derived from
+/// the purl alone, like PyPI's file URLs).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PurlUrls {
+    pub web_url: Option<String>,
+    pub download_url: Option<String>,
+}
+
+/// The web and download URLs of the package, in the spirit of the `purl2url` mappings of other
+/// purl tooling. Versioned URLs are used when the purl has a version, the package's main page
+/// otherwise (which download URLs have no counterpart for). A `download_url` qualifier is taken
+/// as is, and a `repository_url` one replaces the default host where the URLs can be derived from
+/// it (no URLs otherwise).
+pub fn urls(purl: &Purl) -> PurlUrls {
+    let namespace = purl
+        .namespace
+        .as_canonical()
+        .iter()
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect::<Vec<_>>()
+        .join("/");
+    // name and version are percent-encoded in the purl already, as they are to be in the URLs
+    let name = purl.name.as_str();
+    let version = purl.version.as_deref();
+    let qualifiers = PurlQualifiers::new_naive(purl.qualifiers.as_deref().unwrap_or_default());
+    let qualifier = |key: &str| purl_data::qualifier_value(&qualifiers, key);
+    let repository_url = |default: &str| {
+        qualifier("repository_url").map_or(default.to_string(), |repository_url| {
+            purl_data::repository_url_as_base_url(repository_url)
+        })
+    };
+    let with_namespace = |separator: &str| {
+        if namespace.is_empty() {
+            name.to_string()
+        } else {
+            format!("{namespace}{separator}{name}")
+        }
+    };
+
+    let (web_url, download_url) = match (&purl.typex, version) {
+        // the pages of other registries cannot be told from their index URL, and crates.io's or
+        // PyPI's would be about a different package
+        (PurlType::Cargo | PurlType::Pypi, _) if qualifier("repository_url").is_some() => {
+            (None, None)
+        }
+        (PurlType::Cargo, None) => (Some(format!("https://crates.io/crates/{name}")), None),
+        (PurlType::Cargo, Some(version)) => (
+            Some(format!("https://crates.io/crates/{name}/{version}")),
+            Some(format!(
+                "https://crates.io/api/v1/crates/{name}/{version}/download"
+            )),
+        ),
+        (PurlType::Npm, _) => {
+            // the scope reads better unencoded, and npm accepts it either way
+            let full_name = with_namespace("/").replace("%40", "@");
+            let web_url = match version {
+                Some(version) => format!("https://www.npmjs.com/package/{full_name}/v/{version}"),
+                None => format!("https://www.npmjs.com/package/{full_name}"),
+            };
+            let download_url = version.map(|version| {
+                format!(
+                    "{}/{full_name}/-/{name}-{version}.tgz",
+                    repository_url("https://registry.npmjs.org")
+                )
+            });
+            (Some(web_url), download_url)
+        }
+        (PurlType::Pypi, None) => (Some(format!("https://pypi.org/project/{name}/")), None),
+        (PurlType::Pypi, Some(version)) => (
+            Some(format!("https://pypi.org/project/{name}/{version}/")),
+            None,
+        ),
+        (PurlType::Maven, _) if !namespace.is_empty() => {
+            let group = namespace.replace('/', ".");
+            let web_url = match version {
+                Some(version) => {
+                    format!("https://search.maven.org/artifact/{group}/{name}/{version}/jar")
+                }
+                None => format!("https://search.maven.org/artifact/{group}/{name}"),
+            };
+            let download_url = version.map(|version| {
+                let classifier = qualifier("classifier")
+                    .map(|classifier| format!("-{classifier}"))
+                    .unwrap_or_default();
+                let extension = qualifier("type").unwrap_or("jar");
+                format!(
+                    "{}/{}/{name}/{version}/{name}-{version}{classifier}.{extension}",
+                    repository_url("https://repo.maven.apache.org/maven2"),
+                    group.replace('.', "/")
+                )
+            });
+            (Some(web_url), download_url)
+        }
+        (PurlType::Github, _) if !namespace.is_empty() => {
+            let repo = format!("https://github.com/{namespace}/{name}");
+            match version {
+                Some(version) => (
+                    Some(format!("{repo}/tree/{version}")),
+                    Some(format!("{repo}/archive/{version}.tar.gz")),
+                ),
+                None => (Some(repo), None),
+            }
+        }
+        (PurlType::Gitlab, _) if !namespace.is_empty() => {
+            let repo = format!(
+                "{}/{namespace}/{name}",
+                repository_url("https://gitlab.com")
+            );
+            match version {
+                Some(version) => (
+                    Some(format!("{repo}/-/tree/{version}")),
+                    Some(format!(
+                        "{repo}/-/archive/{version}/{name}-{version}.tar.gz"
+                    )),
+                ),
+                None => (Some(repo), None),
+            }
+        }
+        (PurlType::Bitbucket, _) if !namespace.is_empty() => {
+            let repo = format!("https://bitbucket.org/{namespace}/{name}");
+            match version {
+                Some(version) => (
+                    Some(format!("{repo}/src/{version}")),
+                    Some(format!("{repo}/get/{version}.tar.gz")),
+                ),
+                None => (Some(repo), None),
+            }
+        }
+        (PurlType::Docker, _) if qualifier("repository_url").is_none() => {
+            // official images are the namespace-less (or 'library') ones
+            let web_url = match namespace.as_str() {
+                "" | "library" => format!("https://hub.docker.com/_/{name}"),
+                _ => format!("https://hub.docker.com/r/{namespace}/{name}"),
+            };
+            (Some(web_url), None)
+        }
+        (PurlType::Golang, _) => {
+            let web_url = match version {
+                Some(version) => format!("https://pkg.go.dev/{}@{version}", with_namespace("/")),
+                None => format!("https://pkg.go.dev/{}", with_namespace("/")),
+            };
+            (Some(web_url), None)
+        }
+        (PurlType::Nuget, None) => (Some(format!("https://www.nuget.org/packages/{name}")), None),
+        (PurlType::Nuget, Some(version)) => {
+            let (lower_name, lower_version) = (name.to_lowercase(), version.to_lowercase());
+            (
+                Some(format!("https://www.nuget.org/packages/{name}/{version}")),
+                Some(format!(
+                    "https://api.nuget.org/v3-flatcontainer/{lower_name}/{lower_version}/{lower_name}.{lower_version}.nupkg"
+                )),
+            )
+        }
+        (PurlType::Gem, None) => (Some(format!("https://rubygems.org/gems/{name}")), None),
+        (PurlType::Gem, Some(version)) => (
+            Some(format!(
+                "https://rubygems.org/gems/{name}/versions/{version}"
+            )),
+            Some(format!(
+                "https://rubygems.org/downloads/{name}-{version}.gem"
+            )),
+        ),
+        (PurlType::Hex, None) => (Some(format!("https://hex.pm/packages/{name}")), None),
+        (PurlType::Hex, Some(version)) => (
+            Some(format!("https://hex.pm/packages/{name}/{version}")),
+            Some(format!("https://repo.hex.pm/tarballs/{name}-{version}.tar")),
+        ),
+        (PurlType::Composer, _) if !namespace.is_empty() => (
+            Some(format!("https://packagist.org/packages/{namespace}/{name}")),
+            None,
+        ),
+        (PurlType::Pub, None) => (Some(format!("https://pub.dev/packages/{name}")), None),
+        (PurlType::Pub, Some(version)) => (
+            Some(format!(
+                "https://pub.dev/packages/{name}/versions/{version}"
+            )),
+            None,
+        ),
+        (PurlType::Cran, _) => (
+            Some(format!("https://cran.r-project.org/package={name}")),
+            None,
+        ),
+        (PurlType::Cocoapods, _) => (Some(format!("https://cocoapods.org/pods/{name}")), None),
+        _ => (None, None),
+    };
+    // the spec's `download_url` qualifier names the artifact itself, so it wins over the guess
+    let download_url = qualifier("download_url")
+        .map(str::to_string)
+        .or(download_url);
+    PurlUrls {
+        web_url,
+        download_url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{urls, PurlUrls};
+    use crate::purl_data::Purl;
+
+    use paste::paste;
+
+    macro_rules! test_urls {
+        ($name:ident, $purl:expr, $web_url:expr, $download_url:expr) => {
+            paste! {
+            #[test]
+            fn [<test_urls_ $name>]() {
+                let expected = PurlUrls {
+                    web_url: $web_url.map(str::to_string),
+                    download_url: $download_url.map(str::to_string),
+                };
+                assert_eq!(urls(&Purl::parse($purl).unwrap()), expected);
+            }
+            }
+        };
+    }
+
+    test_urls!(
+        cargo,
+        "pkg:cargo/serde@1.0.195",
+        Some("https://crates.io/crates/serde/1.0.195"),
+        Some("https://crates.io/api/v1/crates/serde/1.0.195/download")
+    );
+    test_urls!(
+        cargo_other_registry,
+        "pkg:cargo/my-crate@0.3.1?repository_url=crates.example.com",
+        None::<&str>,
+        None::<&str>
+    );
+    test_urls!(
+        npm_scoped,
+        "pkg:npm/%40angular/core@17.0.0",
+        Some("https://www.npmjs.com/package/@angular/core/v/17.0.0"),
+        Some("https://registry.npmjs.org/@angular/core/-/core-17.0.0.tgz")
+    );
+    test_urls!(
+        npm_unversioned,
+        "pkg:npm/left-pad",
+        Some("https://www.npmjs.com/package/left-pad"),
+        None::<&str>
+    );
+    test_urls!(
+        maven,
+        "pkg:maven/org.lwjgl/lwjgl@3.3.3?classifier=natives-linux",
+        Some("https://search.maven.org/artifact/org.lwjgl/lwjgl/3.3.3/jar"),
+        Some("https://repo.maven.apache.org/maven2/org/lwjgl/lwjgl/3.3.3/lwjgl-3.3.3-natives-linux.jar")
+    );
+    test_urls!(
+        github,
+        "pkg:github/package-url/purl-spec@v1.0",
+        Some("https://github.com/package-url/purl-spec/tree/v1.0"),
+        Some("https://github.com/package-url/purl-spec/archive/v1.0.tar.gz")
+    );
+    test_urls!(
+        gitlab_self_hosted,
+        "pkg:gitlab/group/project@v2.0?repository_url=gitlab.example.com",
+        Some("https://gitlab.example.com/group/project/-/tree/v2.0"),
+        Some("https://gitlab.example.com/group/project/-/archive/v2.0/project-v2.0.tar.gz")
+    );
+    test_urls!(
+        pypi,
+        "pkg:pypi/requests@2.31.0",
+        Some("https://pypi.org/project/requests/2.31.0/"),
+        None::<&str>
+    );
+    test_urls!(
+        pypi_other_index,
+        "pkg:pypi/requests@2.31.0?repository_url=pypi.example.com/simple",
+        None::<&str>,
+        None::<&str>
+    );
+    test_urls!(
+        docker,
+        "pkg:docker/library/nginx@1.25",
+        Some("https://hub.docker.com/_/nginx"),
+        None::<&str>
+    );
+    test_urls!(
+        download_url_qualifier,
+        "pkg:pypi/requests@2.31.0?download_url=https%3A%2F%2Ffiles.example.com%2Frequests-2.31.0.tar.gz",
+        Some("https://pypi.org/project/requests/2.31.0/"),
+        Some("https://files.example.com/requests-2.31.0.tar.gz")
+    );
+    test_urls!(
+        download_url_qualifier_escaped,
+        "pkg:npm/a@1.0.0?download_url=https%3A%2F%2Fx%2Fa%2520b.tgz",
+        Some("https://www.npmjs.com/package/a/v/1.0.0"),
+        Some("https://x/a%20b.tgz")
+    );
+    test_urls!(
+        unknown,
+        "pkg:generic/openssl@1.1.1",
+        None::<&str>,
+        None::<&str>
+    );
+}
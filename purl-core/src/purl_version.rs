@@ -0,0 +1,423 @@
+use std::cmp::Ordering;
+
+use crate::purl_data::PurlType;
+use crate::purl_eval::VersionWarning;
+
+/// How the versions of a type are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionScheme {
+    /// `MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD]`, tolerating a leading `v` and more or fewer than
+    /// three numeric components.
+    Semver,
+    /// `[EPOCH:]UPSTREAM[-REVISION]`, compared like `dpkg` does.
+    Debian,
+    /// Anything else: runs of digits compare numerically, runs of letters lexically, with the
+    /// usual pre-release markers (`alpha`, `rc`, `SNAPSHOT`, ...) sorting before the release.
+    Generic,
+}
+
+impl VersionScheme {
+    pub fn of(purl_type: &PurlType) -> Self {
+        match purl_type {
+            PurlType::Cargo
+            | PurlType::Npm
+            | PurlType::Hex
+            | PurlType::Pub
+            | PurlType::Golang
+            | PurlType::Nuget
+            | PurlType::Composer => VersionScheme::Semver,
+            PurlType::Deb => VersionScheme::Debian,
+            _ => VersionScheme::Generic,
+        }
+    }
+
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            VersionScheme::Semver => compare_semver(a, b),
+            VersionScheme::Debian => compare_debian(a, b),
+            VersionScheme::Generic => compare_generic(a, b),
+        }
+    }
+}
+
+/// A version to offer for selection, along with what speaks against choosing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionCandidate {
+    pub version: String,
+    pub warning: Option<VersionWarning>,
+    pub latest: bool,
+}
+
+impl VersionCandidate {
+    pub fn new(version: impl Into<String>, warning: Option<VersionWarning>) -> Self {
+        VersionCandidate {
+            version: version.into(),
+            warning,
+            latest: false,
+        }
+    }
+}
+
+/// Sorts the candidates newest-first and puts the latest version on top, which is either the one
+/// the registry declares as such or the newest one without any warning.
+pub fn sort_candidates(
+    purl_type: &PurlType,
+    mut candidates: Vec<VersionCandidate>,
+    latest: Option<&str>,
+) -> Vec<VersionCandidate> {
+    let scheme = VersionScheme::of(purl_type);
+    candidates.sort_by(|a, b| scheme.compare(&b.version, &a.version));
+    candidates.dedup_by(|a, b| a.version == b.version);
+
+    let latest_index = match latest {
+        Some(latest) => candidates.iter().position(|c| c.version == latest),
+        None => candidates.iter().position(|c| c.warning.is_none()),
+    };
+    if let Some(latest_index) = latest_index {
+        let mut latest = candidates.remove(latest_index);
+        latest.latest = true;
+        candidates.insert(0, latest);
+    }
+    candidates
+}
+
+fn compare_semver(a: &str, b: &str) -> Ordering {
+    fn split(version: &str) -> (Vec<&str>, Option<&str>) {
+        let version = version.strip_prefix('v').unwrap_or(version);
+        let version = version.split('+').next().unwrap_or_default();
+        match version.split_once('-') {
+            Some((release, prerelease)) => (release.split('.').collect(), Some(prerelease)),
+            None => (version.split('.').collect(), None),
+        }
+    }
+    let (a_release, a_prerelease) = split(a);
+    let (b_release, b_prerelease) = split(b);
+
+    let components = a_release.len().max(b_release.len());
+    for i in 0..components {
+        let ordering = compare_identifier(
+            a_release.get(i).copied().unwrap_or("0"),
+            b_release.get(i).copied().unwrap_or("0"),
+        );
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+
+    match (a_prerelease, b_prerelease) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let (a, b) = (
+                a.split('.').collect::<Vec<_>>(),
+                b.split('.').collect::<Vec<_>>(),
+            );
+            a.iter()
+                .zip(b.iter())
+                .map(|(a, b)| compare_identifier(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+    }
+}
+
+/// Numeric identifiers compare numerically and sort before alphanumeric ones.
+fn compare_identifier(a: &str, b: &str) -> Ordering {
+    match (a.parse::<u64>(), b.parse::<u64>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+fn compare_debian(a: &str, b: &str) -> Ordering {
+    fn split(version: &str) -> (u64, &str, &str) {
+        let (epoch, rest) = match version.split_once(':') {
+            Some((epoch, rest)) => (epoch.parse().unwrap_or(0), rest),
+            None => (0, version),
+        };
+        match rest.rsplit_once('-') {
+            Some((upstream, revision)) => (epoch, upstream, revision),
+            None => (epoch, rest, ""),
+        }
+    }
+    let (a_epoch, a_upstream, a_revision) = split(a);
+    let (b_epoch, b_upstream, b_revision) = split(b);
+    a_epoch
+        .cmp(&b_epoch)
+        .then_with(|| dpkg_verrevcmp(a_upstream, b_upstream))
+        .then_with(|| dpkg_verrevcmp(a_revision, b_revision))
+}
+
+/// The comparison of version parts as implemented by `dpkg`: alternating non-digit parts (where
+/// `~` sorts before anything, even the end) and numeric parts.
+fn dpkg_verrevcmp(a: &str, b: &str) -> Ordering {
+    fn order(c: Option<&u8>) -> i32 {
+        match c {
+            None => 0,
+            Some(b'~') => -1,
+            Some(c) if c.is_ascii_digit() => 0,
+            Some(c) if c.is_ascii_alphabetic() => *c as i32,
+            Some(c) => *c as i32 + 256,
+        }
+    }
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        while (i < a.len() && !a[i].is_ascii_digit()) || (j < b.len() && !b[j].is_ascii_digit()) {
+            let (a_order, b_order) = (order(a.get(i)), order(b.get(j)));
+            if a_order != b_order {
+                return a_order.cmp(&b_order);
+            }
+            i += 1;
+            j += 1;
+        }
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(j) == Some(&b'0') {
+            j += 1;
+        }
+        let mut first_difference = Ordering::Equal;
+        while i < a.len() && a[i].is_ascii_digit() && j < b.len() && b[j].is_ascii_digit() {
+            if first_difference.is_eq() {
+                first_difference = a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+        if i < a.len() && a[i].is_ascii_digit() {
+            return Ordering::Greater;
+        }
+        if j < b.len() && b[j].is_ascii_digit() {
+            return Ordering::Less;
+        }
+        if first_difference.is_ne() {
+            return first_difference;
+        }
+    }
+    Ordering::Equal
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Number(u64),
+    Word(String),
+}
+
+fn tokenize(version: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = version.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                number.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Number(number.parse().unwrap_or(u64::MAX)));
+        } else if c.is_alphabetic() {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_alphabetic()) {
+                word.extend(c.to_lowercase());
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        } else {
+            chars.next();
+        }
+    }
+    tokens
+}
+
+/// Where a word ranks relative to the release itself (0), if it is one of the common markers.
+fn word_rank(word: &str) -> Option<i32> {
+    match word {
+        "dev" | "snapshot" => Some(-5),
+        "a" | "alpha" => Some(-4),
+        "b" | "beta" => Some(-3),
+        "m" | "milestone" | "pre" | "preview" => Some(-2),
+        "c" | "rc" | "cr" => Some(-1),
+        "ga" | "final" | "release" => Some(0),
+        _ => None,
+    }
+}
+
+fn compare_generic(a: &str, b: &str) -> Ordering {
+    let (a, b) = (tokenize(a), tokenize(b));
+    for i in 0..a.len().max(b.len()) {
+        let ordering = match (a.get(i), b.get(i)) {
+            (Some(Token::Number(a)), Some(Token::Number(b))) => a.cmp(b),
+            (Some(Token::Word(a)), Some(Token::Word(b))) => match (word_rank(a), word_rank(b)) {
+                (Some(a_rank), Some(b_rank)) => a_rank.cmp(&b_rank),
+                _ => a.cmp(b),
+            },
+            // 1.0.1 comes after 1.0-beta, but 1.0-beta before 1.0 (and 1.0-sp1 after it)
+            (Some(Token::Number(_)), Some(Token::Word(_))) => Ordering::Greater,
+            (Some(Token::Word(_)), Some(Token::Number(_))) => Ordering::Less,
+            (Some(Token::Number(_)), None) => Ordering::Greater,
+            (None, Some(Token::Number(_))) => Ordering::Less,
+            (Some(Token::Word(a)), None) => word_rank(a).unwrap_or(1).cmp(&0),
+            (None, Some(Token::Word(b))) => 0.cmp(&word_rank(b).unwrap_or(1)),
+            (None, None) => Ordering::Equal,
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{sort_candidates, VersionCandidate, VersionScheme};
+    use crate::purl_data::PurlType;
+    use crate::purl_eval::VersionWarning;
+
+    use paste::paste;
+
+    macro_rules! test_cmp {
+        ($name:ident, $scheme:expr, $l:expr, $r:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_cmp_ $name>]() {
+                let scheme = $scheme;
+                let (l, r) = ($l, $r);
+                let expected = $expect;
+                let result = scheme.compare(l, r);
+                if result != expected {
+                    panic!("compare({l}, {r}) with {scheme:?} expects {expected:?} got {result:?}")
+                }
+            }
+            }
+        };
+    }
+
+    test_cmp!(
+        semver_minor,
+        VersionScheme::Semver,
+        "1.10.0",
+        "1.9.3",
+        Ordering::Greater
+    );
+    test_cmp!(
+        semver_prerelease,
+        VersionScheme::Semver,
+        "2.0.0-rc.1",
+        "2.0.0",
+        Ordering::Less
+    );
+    test_cmp!(
+        semver_prerelease_numeric,
+        VersionScheme::Semver,
+        "1.0.0-beta.11",
+        "1.0.0-beta.2",
+        Ordering::Greater
+    );
+    test_cmp!(
+        semver_v_prefix,
+        VersionScheme::Semver,
+        "v1.2.3",
+        "1.2.3",
+        Ordering::Equal
+    );
+    test_cmp!(
+        semver_build,
+        VersionScheme::Semver,
+        "1.2.3+abc",
+        "1.2.3",
+        Ordering::Equal
+    );
+    test_cmp!(
+        debian_epoch,
+        VersionScheme::Debian,
+        "1:1.0-1",
+        "2.0-1",
+        Ordering::Greater
+    );
+    test_cmp!(
+        debian_tilde,
+        VersionScheme::Debian,
+        "1.0~rc1-1",
+        "1.0-1",
+        Ordering::Less
+    );
+    test_cmp!(
+        debian_revision,
+        VersionScheme::Debian,
+        "7.88.1-10+deb12u5",
+        "7.88.1-10",
+        Ordering::Greater
+    );
+    test_cmp!(
+        debian_numeric,
+        VersionScheme::Debian,
+        "1.10",
+        "1.9",
+        Ordering::Greater
+    );
+    test_cmp!(
+        generic_numeric,
+        VersionScheme::Generic,
+        "3.10",
+        "3.9",
+        Ordering::Greater
+    );
+    test_cmp!(
+        generic_snapshot,
+        VersionScheme::Generic,
+        "1.0-SNAPSHOT",
+        "1.0",
+        Ordering::Less
+    );
+    test_cmp!(
+        generic_rc_beta,
+        VersionScheme::Generic,
+        "2.0rc1",
+        "2.0b3",
+        Ordering::Greater
+    );
+    test_cmp!(
+        generic_patch_over_pre,
+        VersionScheme::Generic,
+        "1.0.1",
+        "1.0-beta",
+        Ordering::Greater
+    );
+    test_cmp!(
+        generic_post,
+        VersionScheme::Generic,
+        "1.0.post1",
+        "1.0",
+        Ordering::Greater
+    );
+
+    #[test]
+    fn test_sort_candidates() {
+        let candidates = vec![
+            VersionCandidate::new("1.0.0", None),
+            VersionCandidate::new("2.0.0-beta.1", Some(VersionWarning::Prerelease)),
+            VersionCandidate::new("1.2.0", Some(VersionWarning::Yanked)),
+            VersionCandidate::new("1.1.0", None),
+        ];
+
+        let sorted = sort_candidates(&PurlType::Cargo, candidates.clone(), None);
+        assert_eq!(
+            sorted
+                .iter()
+                .map(|c| c.version.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1.1.0", "2.0.0-beta.1", "1.2.0", "1.0.0"]
+        );
+        assert!(sorted[0].latest);
+        assert!(!sorted[1].latest);
+
+        let sorted = sort_candidates(&PurlType::Cargo, candidates, Some("1.0.0"));
+        assert_eq!(sorted[0].version, "1.0.0");
+        assert!(sorted[0].latest);
+    }
+}
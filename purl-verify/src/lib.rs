@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod purl_cache;
+pub mod purl_check;
+pub mod purl_eval_apk;
+pub mod purl_eval_bitbucket;
+pub mod purl_eval_conda;
+pub mod purl_eval_cran;
+pub mod purl_eval_cratesio;
+pub mod purl_eval_deb;
+pub mod purl_eval_github;
+pub mod purl_eval_gitlab;
+pub mod purl_eval_hackage;
+pub mod purl_eval_hex;
+pub mod purl_eval_huggingface;
+pub mod purl_eval_index;
+pub mod purl_eval_npm;
+pub mod purl_eval_nuget;
+pub mod purl_eval_packagist;
+pub mod purl_eval_pub;
+pub mod purl_eval_rubygems;
+pub mod purl_fetch;
+
+/// What the checks fail with, which callers may downcast to the checks' own error types (such as
+/// [`purl_eval_github::GithubCheckErr`]) where they want to react to specific errors.
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+pub type Result<T> = std::result::Result<T, Error>;
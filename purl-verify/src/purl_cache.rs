@@ -0,0 +1,310 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A response as it is kept in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub status: u16,
+    /// With lowercase names.
+    pub headers: HashMap<String, String>,
+    pub body: Rc<Vec<u8>>,
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+    /// Whether the request carried credentials, making the response possibly private (to be kept
+    /// out of storage that outlives the session).
+    pub authorized: bool,
+}
+
+/// Where cached responses are kept, keyed by [`cache_key`].
+///
+/// The browser app persists entries in `localStorage`; anything not running in a browser can
+/// plug in its own storage (in memory, on disk, ...) through [`set_cache`].
+pub trait ResponseCache {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+    fn put(&self, key: &str, entry: CacheEntry);
+    fn clear(&self);
+}
+
+/// How long responses are considered fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// For successful responses.
+    pub ttl: chrono::Duration,
+    /// For responses saying that something does not exist, which registries tend to change more
+    /// quickly (by someone publishing the missing thing, for example).
+    pub negative_ttl: chrono::Duration,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        CachePolicy {
+            ttl: chrono::Duration::hours(1),
+            negative_ttl: chrono::Duration::minutes(5),
+        }
+    }
+}
+
+impl CachePolicy {
+    /// The time an entry with the given status may be used for, if it may be cached at all.
+    ///
+    /// Errors such as rate limiting are never cached, as they are not about the requested
+    /// resource.
+    pub fn ttl_for(&self, status: u16) -> Option<chrono::Duration> {
+        match status {
+            200..=299 => Some(self.ttl),
+            404 | 410 => Some(self.negative_ttl),
+            _ => None,
+        }
+    }
+
+    pub fn is_fresh(&self, entry: &CacheEntry, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.ttl_for(entry.status)
+            .is_some_and(|ttl| now - entry.fetched_at < ttl)
+    }
+}
+
+/// The key a request's response is cached under: the URL, and a hash of the request's headers
+/// if it has any, so that responses to anonymous and authenticated requests (which may well
+/// differ, think of rate limits and private repositories) are kept apart without the key giving
+/// away the token.
+///
+/// Keys are persisted, so the hash is one that stays the same across Rust releases (64-bit
+/// FNV-1a), unlike the standard library's.
+pub fn cache_key(url: &str, headers: &[(String, String)]) -> String {
+    if headers.is_empty() {
+        return url.to_string();
+    }
+    let mut headers = headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value))
+        .collect::<Vec<_>>();
+    headers.sort();
+    let hash = headers
+        .iter()
+        .flat_map(|(name, value)| [name.as_bytes(), b":", value.as_bytes(), b"\n"])
+        .flatten()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+        });
+    format!("{url} {hash:016x}")
+}
+
+thread_local! {
+    static CACHE: RefCell<Rc<dyn ResponseCache>> = RefCell::new(Rc::new(MemoryCache::default()));
+    static POLICY: RefCell<CachePolicy> = RefCell::new(CachePolicy::default());
+}
+
+/// Replaces the cache all requests go through (an in-memory one by default).
+pub fn set_cache(cache: Rc<dyn ResponseCache>) {
+    CACHE.with(|c| *c.borrow_mut() = cache);
+}
+
+pub fn set_policy(policy: CachePolicy) {
+    POLICY.with(|p| *p.borrow_mut() = policy);
+}
+
+/// Gets a fresh entry for the key, if there is one.
+pub fn lookup(key: &str) -> Option<CacheEntry> {
+    let policy = POLICY.with(|p| *p.borrow());
+    CACHE
+        .with(|c| c.borrow().get(key))
+        .filter(|entry| policy.is_fresh(entry, chrono::Utc::now()))
+}
+
+/// Stores the entry if its status may be cached.
+pub fn store(key: &str, entry: CacheEntry) {
+    let policy = POLICY.with(|p| *p.borrow());
+    if policy.ttl_for(entry.status).is_some() {
+        CACHE.with(|c| c.borrow().put(key, entry));
+    }
+}
+
+pub fn clear() {
+    CACHE.with(|c| c.borrow().clear());
+}
+
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: RefCell<HashMap<String, CacheEntry>>,
+}
+
+impl ResponseCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        self.entries.borrow_mut().insert(key.to_string(), entry);
+    }
+
+    fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+/// Keeps all entries in memory and the small textual ones in `localStorage` as well, so they
+/// survive reloads (binary index files and the like are too big for it). Responses to requests
+/// with credentials stay in memory only, as they may be about private repositories.
+#[cfg(feature = "web")]
+#[derive(Default)]
+pub struct LocalStorageCache {
+    memory: MemoryCache,
+}
+
+#[cfg(feature = "web")]
+impl LocalStorageCache {
+    const KEY_PREFIX: &'static str = "response-cache:";
+    const MAX_PERSISTED_BODY_SIZE: usize = 512 * 1024;
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    /// Removes the persisted entries that are no longer fresh (or cannot be read anymore), which
+    /// would otherwise pile up until the storage quota is exhausted.
+    fn prune(storage: &web_sys::Storage) {
+        let policy = POLICY.with(|p| *p.borrow());
+        let now = chrono::Utc::now();
+        let stale = (0..storage.length().unwrap_or(0))
+            .filter_map(|i| storage.key(i).ok().flatten())
+            .filter(|key| key.starts_with(Self::KEY_PREFIX))
+            .filter(|key| {
+                let is_fresh = storage
+                    .get_item(key)
+                    .ok()
+                    .flatten()
+                    .and_then(|persisted| serde_json::from_str::<PersistedEntry>(&persisted).ok())
+                    .is_some_and(|persisted| policy.is_fresh(&persisted.into_entry(), now));
+                !is_fresh
+            })
+            .collect::<Vec<_>>();
+        for key in stale {
+            let _ = storage.remove_item(&key);
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+impl ResponseCache for LocalStorageCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        if let Some(entry) = self.memory.get(key) {
+            return Some(entry);
+        }
+        let persisted = Self::storage()?
+            .get_item(&format!("{}{key}", Self::KEY_PREFIX))
+            .ok()??;
+        let entry = serde_json::from_str::<PersistedEntry>(&persisted)
+            .ok()?
+            .into_entry();
+        self.memory.put(key, entry.clone());
+        Some(entry)
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        if !entry.authorized && entry.body.len() <= Self::MAX_PERSISTED_BODY_SIZE {
+            if let (Some(storage), Some(persisted)) = (Self::storage(), PersistedEntry::of(&entry))
+            {
+                if let Ok(persisted) = serde_json::to_string(&persisted) {
+                    Self::prune(&storage);
+                    // exceeding the storage quota is not worth more than a note, the entry is
+                    // still cached in memory
+                    if let Err(e) =
+                        storage.set_item(&format!("{}{key}", Self::KEY_PREFIX), &persisted)
+                    {
+                        log::debug!("could not persist cache entry for '{key}' ({e:?})");
+                    }
+                }
+            }
+        }
+        self.memory.put(key, entry);
+    }
+
+    fn clear(&self) {
+        self.memory.clear();
+        if let Some(storage) = Self::storage() {
+            let keys = (0..storage.length().unwrap_or(0))
+                .filter_map(|i| storage.key(i).ok().flatten())
+                .filter(|key| key.starts_with(Self::KEY_PREFIX))
+                .collect::<Vec<_>>();
+            for key in keys {
+                let _ = storage.remove_item(&key);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedEntry {
+    status: u16,
+    headers: HashMap<String, String>,
+    body: String,
+    fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[cfg(feature = "web")]
+impl PersistedEntry {
+    /// Only textual bodies are persisted.
+    fn of(entry: &CacheEntry) -> Option<Self> {
+        Some(PersistedEntry {
+            status: entry.status,
+            headers: entry.headers.clone(),
+            body: String::from_utf8(entry.body.to_vec()).ok()?,
+            fetched_at: entry.fetched_at,
+        })
+    }
+
+    fn into_entry(self) -> CacheEntry {
+        CacheEntry {
+            status: self.status,
+            headers: self.headers,
+            body: Rc::new(self.body.into_bytes()),
+            fetched_at: self.fetched_at,
+            authorized: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use super::{cache_key, CacheEntry, CachePolicy};
+
+    #[test]
+    fn test_cache_key() {
+        let url = "https://api.github.com/repos/package-url/purl-spec";
+        let token = |token: &str| vec![("Authorization".to_string(), format!("Bearer {token}"))];
+        assert_eq!(cache_key(url, &[]), url);
+        assert_ne!(cache_key(url, &token("a")), cache_key(url, &[]));
+        assert_ne!(cache_key(url, &token("a")), cache_key(url, &token("b")));
+        assert_eq!(cache_key(url, &token("a")), cache_key(url, &token("a")));
+        assert!(!cache_key(url, &token("secret")).contains("secret"));
+        // persisted keys must not change with the toolchain
+        assert_eq!(
+            cache_key(url, &token("a")),
+            format!("{url} c3865953d1f30110")
+        );
+    }
+
+    #[test]
+    fn test_policy() {
+        let policy = CachePolicy::default();
+        let now = chrono::Utc::now();
+        let entry = |status, age| CacheEntry {
+            status,
+            headers: HashMap::new(),
+            body: Rc::new(vec![]),
+            fetched_at: now - age,
+            authorized: false,
+        };
+
+        assert!(policy.is_fresh(&entry(200, chrono::Duration::minutes(30)), now));
+        assert!(!policy.is_fresh(&entry(200, chrono::Duration::minutes(90)), now));
+        assert!(policy.is_fresh(&entry(404, chrono::Duration::minutes(1)), now));
+        assert!(!policy.is_fresh(&entry(404, chrono::Duration::minutes(30)), now));
+        assert!(!policy.is_fresh(&entry(403, chrono::Duration::zero()), now));
+        assert_eq!(policy.ttl_for(500), None);
+    }
+}
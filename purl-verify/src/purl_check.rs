@@ -0,0 +1,1436 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use itertools::Itertools;
+use purl_core::purl_data::{self, Purl, PurlComponent};
+use purl_core::{purl_eval, purl_suggest, purl_version};
+
+use crate::{
+    purl_eval_apk, purl_eval_bitbucket, purl_eval_conda, purl_eval_cran, purl_eval_cratesio,
+    purl_eval_deb, purl_eval_github, purl_eval_gitlab, purl_eval_hackage, purl_eval_hex,
+    purl_eval_huggingface, purl_eval_index, purl_eval_npm, purl_eval_nuget, purl_eval_packagist,
+    purl_eval_pub, purl_eval_rubygems,
+};
+
+/// The registries and indexes a purl can be checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckType {
+    AlpineIndex,
+    Bitbucket,
+    Conda,
+    Cran,
+    CratesIo,
+    CratesIoIndex,
+    DebianIndex,
+    Github,
+    Gitlab,
+    Hackage,
+    Hex,
+    Huggingface,
+    Npm,
+    Nuget,
+    Packagist,
+    PubDev,
+    Rubygems,
+}
+
+impl CheckType {
+    pub const ALL: [CheckType; 17] = [
+        CheckType::AlpineIndex,
+        CheckType::Bitbucket,
+        CheckType::Conda,
+        CheckType::Cran,
+        CheckType::CratesIo,
+        CheckType::CratesIoIndex,
+        CheckType::DebianIndex,
+        CheckType::Github,
+        CheckType::Gitlab,
+        CheckType::Hackage,
+        CheckType::Hex,
+        CheckType::Huggingface,
+        CheckType::Npm,
+        CheckType::Nuget,
+        CheckType::Packagist,
+        CheckType::PubDev,
+        CheckType::Rubygems,
+    ];
+
+    /// How the check is referred to in persisted settings and on the command line.
+    pub fn id(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl std::fmt::Display for CheckType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckType::AlpineIndex => write!(f, "Alpine APKINDEX"),
+            CheckType::Bitbucket => write!(f, "api.bitbucket.org"),
+            CheckType::Conda => write!(f, "conda repodata.json"),
+            CheckType::Cran => write!(f, "crandb.r-pkg.org"),
+            CheckType::CratesIo => write!(f, "crates.io/api/v1"),
+            CheckType::CratesIoIndex => write!(f, "crates sparse index"),
+            CheckType::DebianIndex => write!(f, "Debian package index"),
+            CheckType::Github => write!(f, "api.github.com"),
+            CheckType::Gitlab => write!(f, "GitLab API v4"),
+            CheckType::Hackage => write!(f, "hackage.haskell.org"),
+            CheckType::Hex => write!(f, "hex.pm/api"),
+            CheckType::Huggingface => write!(f, "Hugging Face Hub API"),
+            CheckType::Npm => write!(f, "registry.npmjs.org"),
+            CheckType::Nuget => write!(f, "api.nuget.org"),
+            CheckType::Packagist => write!(f, "repo.packagist.org"),
+            CheckType::PubDev => write!(f, "pub.dev/api"),
+            CheckType::Rubygems => write!(f, "rubygems.org/api/v1"),
+        }
+    }
+}
+
+/// Settings and local data the checks take into account; unset settings fall back to the
+/// public registries.
+#[derive(Clone, Default)]
+pub struct CheckOptions {
+    pub github_token: Option<String>,
+    pub gitlab_base_url: Option<String>,
+    pub huggingface_endpoint: Option<String>,
+    pub deb_mirror: Option<String>,
+    pub apk_mirror: Option<String>,
+    /// Whether crates are looked up in the sparse index rather than through the crates.io API.
+    pub cratesio_sparse_index: bool,
+    /// Index files that take precedence over fetching from a mirror.
+    pub local_indexes: Vec<(
+        purl_eval_index::IndexFormat,
+        Rc<Vec<purl_eval_index::IndexEntry>>,
+    )>,
+    pub local_repodata: Vec<Rc<purl_eval_conda::CondaRepodata>>,
+    pub local_crate_records: Vec<Rc<Vec<purl_eval_cratesio::CratesioIndexRecord>>>,
+}
+
+/// Receives what a check finds out, as it finds it out.
+pub trait CheckReport {
+    fn namespace(&self, result: purl_eval::EvalResult);
+    fn name(&self, result: purl_eval::EvalResult);
+    fn version(&self, result: purl_eval::EvalResult);
+    /// Something that kept the check from completing which the user can act on.
+    fn warning(&self, warning: String);
+    fn metadata(&self, metadata: purl_eval::RegistryMetadata);
+    /// The versions the registry knows, sorted for display.
+    fn version_candidates(&self, candidates: Vec<purl_version::VersionCandidate>);
+    /// Close matches for a name that was not found, ranked.
+    fn name_suggestions(&self, suggestions: Vec<purl_suggest::NameSuggestion>);
+    /// The check currently waiting for a response, if any.
+    fn active_check(&self, check: Option<CheckType>);
+}
+
+/// The check a purl needs to go online for, unless it can be answered from local data.
+pub fn online_check(purl: &Purl, options: &CheckOptions) -> Option<CheckType> {
+    let q = purl_data::PurlQualifiers::new_naive(purl.qualifiers.as_deref().unwrap_or_default());
+    let has_local_index = |format| {
+        options
+            .local_indexes
+            .iter()
+            .any(|(index_format, _)| *index_format == format)
+    };
+    match purl.typex {
+        purl_data::PurlType::Cargo if !options.local_crate_records.is_empty() => None,
+        purl_data::PurlType::Cargo
+            if purl_data::qualifier_value(&q, "repository_url").is_some()
+                || options.cratesio_sparse_index =>
+        {
+            Some(CheckType::CratesIoIndex)
+        }
+        purl_data::PurlType::Cargo => Some(CheckType::CratesIo),
+        purl_data::PurlType::Github => Some(CheckType::Github),
+        purl_data::PurlType::Gitlab => Some(CheckType::Gitlab),
+        purl_data::PurlType::Bitbucket => Some(CheckType::Bitbucket),
+        purl_data::PurlType::Npm => Some(CheckType::Npm),
+        purl_data::PurlType::Nuget => Some(CheckType::Nuget),
+        purl_data::PurlType::Gem => Some(CheckType::Rubygems),
+        purl_data::PurlType::Composer => Some(CheckType::Packagist),
+        purl_data::PurlType::Hex => Some(CheckType::Hex),
+        purl_data::PurlType::Pub => Some(CheckType::PubDev),
+        purl_data::PurlType::Hackage => Some(CheckType::Hackage),
+        purl_data::PurlType::Cran => Some(CheckType::Cran),
+        purl_data::PurlType::Deb if !has_local_index(purl_eval_index::IndexFormat::Deb) => {
+            Some(CheckType::DebianIndex)
+        }
+        purl_data::PurlType::Apk if !has_local_index(purl_eval_index::IndexFormat::Apk) => {
+            Some(CheckType::AlpineIndex)
+        }
+        purl_data::PurlType::Conda if options.local_repodata.is_empty() => Some(CheckType::Conda),
+        purl_data::PurlType::Huggingface => Some(CheckType::Huggingface),
+        _ => None,
+    }
+}
+
+/// Whether the namespace of the type is something the registry checks verify, rather than just
+/// a part of the name.
+pub fn checks_namespace(typex: &purl_data::PurlType) -> bool {
+    matches!(
+        typex,
+        purl_data::PurlType::Github
+            | purl_data::PurlType::Gitlab
+            | purl_data::PurlType::Bitbucket
+            | purl_data::PurlType::Composer
+    )
+}
+
+/// Checks the purl against the registry of its type, reporting the results component by
+/// component (types without a registry check report nothing).
+///
+/// The purl's components are expected to have been evaluated offline already; the results only
+/// ever refine that evaluation.
+pub async fn check(purl: &Purl, options: &CheckOptions, report: &dyn CheckReport) {
+    let t = purl.typex.clone();
+    let ns = purl.namespace.join("/");
+    let n = purl.name.clone();
+    let v = purl.version.clone();
+    let q = purl_data::PurlQualifiers::new_naive(purl.qualifiers.as_deref().unwrap_or_default());
+
+    let offer_candidates = |candidates, latest: Option<&str>| {
+        report.version_candidates(purl_version::sort_candidates(&t, candidates, latest))
+    };
+    let searched_name = urlencoding::decode(&n).unwrap_or_default().into_owned();
+    let offer_suggestions = |results: crate::Result<_>| match results {
+        Ok(results) => report.name_suggestions(purl_suggest::rank(&searched_name, results)),
+        Err(e) => log::warn!("an unexpected error occurred searching for similar names ({e})"),
+    };
+
+    match t {
+        purl_data::PurlType::Cargo => {
+            let local_records = options
+                .local_crate_records
+                .iter()
+                .flat_map(|records| records.iter())
+                .filter(|record| record.name.eq_ignore_ascii_case(&n))
+                .cloned()
+                .collect::<Vec<_>>();
+            let has_local_records = !options.local_crate_records.is_empty();
+            let repository_url = purl_data::qualifier_value(&q, "repository_url");
+            let (versions, source) = if has_local_records {
+                (
+                    Some(local_records)
+                        .filter(|records| !records.is_empty())
+                        .map(|records| {
+                            // index files list versions in the order of publication
+                            records
+                                .into_iter()
+                                .rev()
+                                .map(purl_eval_cratesio::CratesioVersion::from)
+                                .collect::<Vec<_>>()
+                        }),
+                    "the local index files".to_string(),
+                )
+            } else if repository_url.is_some() || options.cratesio_sparse_index {
+                report.active_check(Some(CheckType::CratesIoIndex));
+                let Some(index_url) = purl_eval_cratesio::sparse_index_url(repository_url) else {
+                    report.warning(format!(
+                        "could not check {} (only sparse indexes can be looked up)",
+                        repository_url.unwrap_or_default()
+                    ));
+                    report.active_check(None);
+                    return;
+                };
+                match purl_eval_cratesio::get_index_records(&index_url, &n).await {
+                    Ok(records) => (
+                        records.map(|records| {
+                            records
+                                .into_iter()
+                                .rev()
+                                .map(purl_eval_cratesio::CratesioVersion::from)
+                                .collect()
+                        }),
+                        index_url,
+                    ),
+                    Err(e) => {
+                        report.warning(format!("could not check {index_url} ({e})"));
+                        report.active_check(None);
+                        return;
+                    }
+                }
+            } else {
+                report.active_check(Some(CheckType::CratesIo));
+                match purl_eval_cratesio::get_versions(&n).await {
+                    Ok(versions) => (versions, "crates.io".to_string()),
+                    // the offline evaluation stands, not finding out is not 'not found'
+                    Err(e) => {
+                        report.warning(format!("could not check crates.io ({e})"));
+                        report.active_check(None);
+                        return;
+                    }
+                }
+            };
+
+            if let Some(versions) = versions {
+                let metadata = purl_eval_cratesio::metadata(&source, &versions, v.as_deref());
+                report.metadata(metadata.clone());
+                offer_candidates(
+                    purl_eval_cratesio::version_candidates(&versions),
+                    metadata.latest_version.as_deref(),
+                );
+                report.name(purl_eval::EvalResult {
+                    level: purl_eval::EvalResultLevel::Verified,
+                    explanation: format!("exists on {source}"),
+                });
+                if let Some(v) = v {
+                    if versions.iter().any(|version| version.num == v) {
+                        report.version(metadata.adjust_version_result(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("exists on {source}"),
+                        }))
+                    } else {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation: format!("not found on {source}"),
+                        })
+                    }
+                }
+            } else {
+                report.name(purl_eval::EvalResult {
+                    level: purl_eval::EvalResultLevel::AtLeastValid,
+                    explanation: format!("not found on {source}"),
+                });
+                // only crates.io can be searched, not alternate registries
+                if !has_local_records && repository_url.is_none() {
+                    offer_suggestions(purl_eval_cratesio::search(&searched_name).await);
+                }
+            }
+            report.active_check(None);
+        }
+
+        purl_data::PurlType::Github => {
+            report.active_check(Some(CheckType::Github));
+
+            let token = options.github_token.clone();
+            let token = token.as_deref();
+            // rate limiting and rejected tokens are something the user can act on, so they get
+            // surfaced in the UI; anything else we can only log
+            let report_error =
+                move |e: crate::Error| match e.downcast_ref::<purl_eval_github::GithubCheckErr>() {
+                    Some(
+                        e @ (purl_eval_github::GithubCheckErr::RateLimited { .. }
+                        | purl_eval_github::GithubCheckErr::BadCredentials),
+                    ) => report.warning(e.to_string()),
+                    _ => log::warn!(
+                        "an unexpected error occurred checking for a GitHub repository ({e})"
+                    ),
+                };
+
+            let mut found_version = false;
+            let mut metadata = purl_eval::RegistryMetadata {
+                source: "GitHub".to_string(),
+                ..Default::default()
+            };
+            if let Some(v) = v {
+                match purl_eval_github::repo_exists_with_version(&ns, &n, &v, token).await {
+                    Ok(Some(found)) => {
+                        found_version = true;
+                        if let purl_eval_github::GithubVersionMatch::Release {
+                            prerelease,
+                            draft,
+                            published_at,
+                        } = found
+                        {
+                            metadata.published = published_at;
+                            if prerelease {
+                                metadata
+                                    .warnings
+                                    .push(purl_eval::VersionWarning::Prerelease);
+                            }
+                            if draft {
+                                metadata.warnings.push(purl_eval::VersionWarning::Draft);
+                            }
+                        }
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the version exists on GitHub as a {found}"),
+                        });
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the repository exists on GitHub".to_string(),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the namespace exists on GitHub as a user or org"
+                                .to_string(),
+                        });
+                    }
+                    Ok(None) => {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation:
+                                "the version does not exist on GitHub as a release, tag or commit"
+                                    .to_string(),
+                        });
+                    }
+                    Err(e) => report_error(e),
+                }
+            }
+
+            match purl_eval_github::get_repo(&ns, &n, token).await {
+                Ok(Some(repo)) => {
+                    metadata.description = repo.description;
+                    metadata.license = repo.license.map(|license| license.identifier());
+                    report.metadata(metadata);
+                    match purl_eval_github::get_releases(&ns, &n, token).await {
+                        Ok(releases) => {
+                            offer_candidates(purl_eval_github::version_candidates(&releases), None)
+                        }
+                        Err(e) => report_error(e),
+                    }
+                    if !found_version {
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the repository exists on GitHub".to_string(),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the namespace exists on GitHub as a user or org"
+                                .to_string(),
+                        });
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find the repository on GitHub".to_string(),
+                    });
+                    let owner_exists = match purl_eval_github::user_or_org_exists(&ns, token).await
+                    {
+                        Ok(true) => {
+                            report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "the namespace exists on GitHub as a user or org"
+                                    .to_string(),
+                            });
+                            true
+                        }
+                        Ok(false) => {
+                            report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "did not find this as a user or org on GitHub"
+                                    .to_string(),
+                            });
+                            false
+                        }
+                        Err(e) => {
+                            report_error(e);
+                            false
+                        }
+                    };
+                    // look among the owner's repositories first, it is more likely the name
+                    // than the owner that is off
+                    let mut results = Ok(vec![]);
+                    if owner_exists {
+                        results =
+                            purl_eval_github::search_repos(&searched_name, Some(&ns), token).await;
+                    }
+                    if matches!(&results, Ok(results) if results.is_empty()) {
+                        results = purl_eval_github::search_repos(&searched_name, None, token).await;
+                    }
+                    offer_suggestions(results);
+                }
+                Err(e) => report_error(e),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Gitlab => {
+            report.active_check(Some(CheckType::Gitlab));
+
+            let base_url = match purl_data::qualifier_value(&q, "repository_url") {
+                Some(repository_url) => purl_data::repository_url_as_base_url(repository_url),
+                None => options
+                    .gitlab_base_url
+                    .clone()
+                    .unwrap_or_else(|| purl_eval_gitlab::DEFAULT_BASE_URL.to_string()),
+            };
+
+            let mut found_version = false;
+            if let Some(v) = v {
+                match purl_eval_gitlab::project_exists_with_version(&base_url, &ns, &n, &v).await {
+                    Ok(Some(found)) => {
+                        found_version = true;
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the version exists on {base_url} as a {found}"),
+                        });
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the project exists on {base_url}"),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the namespace exists on {base_url}"),
+                        });
+                    }
+                    Ok(None) => {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation: format!(
+                                "the version does not exist on {base_url} as a tag or commit"
+                            ),
+                        });
+                    }
+                    Err(e) => {
+                        report.warning(format!("could not check {base_url} ({e})"));
+                        report.active_check(None);
+                        return;
+                    }
+                }
+            }
+
+            if !found_version {
+                match purl_eval_gitlab::project_exists(&base_url, &ns, &n).await {
+                    Ok(true) => {
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the project exists on {base_url}"),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the namespace exists on {base_url}"),
+                        });
+                    }
+                    Ok(false) => {
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation: format!(
+                                "did not find the project on {base_url} (or it is private)"
+                            ),
+                        });
+                        match purl_eval_gitlab::namespace_exists(&base_url, &ns).await {
+                            Ok(true) => report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: format!(
+                                    "the namespace exists on {base_url} as a group or user"
+                                ),
+                            }),
+                            Ok(false) => report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: format!(
+                                    "did not find this as a group or user on {base_url}"
+                                ),
+                            }),
+                            Err(e) => report.warning(format!("could not check {base_url} ({e})")),
+                        }
+                    }
+                    Err(e) => report.warning(format!("could not check {base_url} ({e})")),
+                }
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Bitbucket => {
+            report.active_check(Some(CheckType::Bitbucket));
+
+            let mut found_version = false;
+            if let Some(v) = v {
+                match purl_eval_bitbucket::repo_exists_with_version(&ns, &n, &v).await {
+                    Ok(Some(found)) => {
+                        found_version = true;
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the version exists on Bitbucket as a {found}"),
+                        });
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the repository exists on Bitbucket".to_string(),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the workspace exists on Bitbucket".to_string(),
+                        });
+                    }
+                    Ok(None) => {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation:
+                                "the version does not exist on Bitbucket as a tag or commit"
+                                    .to_string(),
+                        });
+                    }
+                    Err(e) => {
+                        report.warning(format!("could not check Bitbucket ({e})"));
+                        report.active_check(None);
+                        return;
+                    }
+                }
+            }
+
+            if !found_version {
+                match purl_eval_bitbucket::repo_exists(&ns, &n).await {
+                    Ok(true) => {
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the repository exists on Bitbucket".to_string(),
+                        });
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "the workspace exists on Bitbucket".to_string(),
+                        });
+                    }
+                    Ok(false) => {
+                        report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::AtLeastValid,
+                            explanation: "did not find the repository on Bitbucket".to_string(),
+                        });
+                        match purl_eval_bitbucket::workspace_exists(&ns).await {
+                            Ok(true) => report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "the workspace exists on Bitbucket".to_string(),
+                            }),
+                            Ok(false) => report.namespace(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "did not find this workspace on Bitbucket".to_string(),
+                            }),
+                            Err(e) => report.warning(format!("could not check Bitbucket ({e})")),
+                        }
+                    }
+                    Err(e) => report.warning(format!("could not check Bitbucket ({e})")),
+                }
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Npm => {
+            report.active_check(Some(CheckType::Npm));
+
+            match purl_eval_npm::get_package(&n).await {
+                Ok(Some(package)) => {
+                    let metadata = package.metadata(v.as_deref());
+                    report.metadata(metadata.clone());
+                    offer_candidates(
+                        package.version_candidates(),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "found on NPM".to_string(),
+                    });
+                    if let Some(version) = v {
+                        if package
+                            .versions
+                            .iter()
+                            .any(|(version_as_key, _)| *version_as_key == version)
+                        {
+                            report.version(metadata.adjust_version_result(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on NPM".to_string(),
+                            }));
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on NPM".to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on NPM".to_string(),
+                    });
+                    offer_suggestions(purl_eval_npm::search(&searched_name).await);
+                }
+                Err(e) => report.warning(format!("could not check NPM ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Nuget => {
+            report.active_check(Some(CheckType::Nuget));
+
+            match purl_eval_nuget::get_versions(&n).await {
+                Ok(Some(versions)) => {
+                    offer_candidates(purl_eval_nuget::version_candidates(&versions), None);
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "found on NuGet".to_string(),
+                    });
+                    if let Some(version) = v {
+                        if versions.contains(&purl_eval_nuget::normalize_version(&version)) {
+                            match purl_eval_nuget::get_version_details(&n, &version).await {
+                                Ok(Some(details)) => {
+                                    report.metadata(details.metadata(&versions));
+                                    let mut findings = vec![];
+                                    if details.listed == Some(false) {
+                                        findings.push("unlisted".to_string());
+                                    }
+                                    if let Some(deprecation) = &details.deprecation {
+                                        findings.push(deprecation.to_string());
+                                    }
+                                    report.version(if findings.is_empty() {
+                                        purl_eval::EvalResult {
+                                            level: purl_eval::EvalResultLevel::Verified,
+                                            explanation: "found on NuGet".to_string(),
+                                        }
+                                    } else {
+                                        purl_eval::EvalResult {
+                                            level: purl_eval::EvalResultLevel::ProbablyOk,
+                                            explanation: format!(
+                                                "found on NuGet, but {}",
+                                                findings.join(" and ")
+                                            ),
+                                        }
+                                    });
+                                }
+                                Ok(None) | Err(_) => report.version(purl_eval::EvalResult {
+                                    level: purl_eval::EvalResultLevel::Verified,
+                                    explanation: "found on NuGet (could not check for deprecation)"
+                                        .to_string(),
+                                }),
+                            }
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on NuGet".to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on NuGet".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check NuGet ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Gem => {
+            report.active_check(Some(CheckType::Rubygems));
+
+            let platform = purl_data::qualifier_value(&q, "platform")
+                .unwrap_or(purl_eval_rubygems::DEFAULT_PLATFORM)
+                .to_string();
+            match purl_eval_rubygems::get_versions(&n).await {
+                Ok(Some(versions)) => {
+                    let metadata = purl_eval_rubygems::metadata(&versions, v.as_deref(), &platform);
+                    offer_candidates(
+                        purl_eval_rubygems::version_candidates(&versions, &platform),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.metadata(metadata);
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "found on RubyGems".to_string(),
+                    });
+                    if let Some(version) = v {
+                        let with_version = versions
+                            .iter()
+                            .filter(|gem_version| gem_version.number == version)
+                            .collect::<Vec<_>>();
+                        if with_version.is_empty() {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on RubyGems (it may have been yanked)"
+                                    .to_string(),
+                            });
+                        } else if with_version
+                            .iter()
+                            .any(|gem_version| gem_version.platform == platform)
+                        {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: format!("found on RubyGems for platform '{platform}'"),
+                            });
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: format!(
+                                    "found on RubyGems, but not for platform '{platform}' (only for {})",
+                                    with_version
+                                        .iter()
+                                        .map(|gem_version| format!("'{}'", gem_version.platform))
+                                        .join(", ")
+                                ),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this gem on RubyGems".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check RubyGems ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Composer => {
+            report.active_check(Some(CheckType::Packagist));
+
+            match purl_eval_packagist::get_versions(&ns, &n).await {
+                Ok(Some(versions)) => {
+                    let metadata = purl_eval_packagist::metadata(&versions, v.as_deref());
+                    offer_candidates(
+                        purl_eval_packagist::version_candidates(&versions),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.metadata(metadata);
+                    // the abandoned status applies to the package, the latest version has it
+                    match versions.first().map(|latest| &latest.abandoned) {
+                        Some(abandoned) if abandoned.is_abandoned() => {
+                            report.name(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::ProbablyOk,
+                                explanation: format!("found on Packagist, but {abandoned}"),
+                            })
+                        }
+                        _ => report.name(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "found on Packagist".to_string(),
+                        }),
+                    }
+                    report.namespace(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "the vendor exists on Packagist".to_string(),
+                    });
+                    if let Some(version) = v {
+                        if versions.iter().any(|p_version| p_version.matches(&version)) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on Packagist".to_string(),
+                            });
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on Packagist (among tagged versions)"
+                                    .to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on Packagist".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check Packagist ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Hex => {
+            report.active_check(Some(CheckType::Hex));
+
+            match purl_eval_hex::get_package(&n).await {
+                Ok(Some(package)) => {
+                    let metadata = package.metadata(v.as_deref());
+                    offer_candidates(
+                        package.version_candidates(),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.metadata(metadata);
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "found on Hex".to_string(),
+                    });
+                    if let Some(version) = v {
+                        if !package.releases.iter().any(|r| r.version == version) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on Hex".to_string(),
+                            });
+                        } else if let Some(retirement) = package.retirements.get(&version) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::ProbablyOk,
+                                explanation: format!("found on Hex, but {retirement}"),
+                            });
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on Hex".to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on Hex".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check Hex ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Pub => {
+            report.active_check(Some(CheckType::PubDev));
+
+            match purl_eval_pub::get_package(&n).await {
+                Ok(Some(package)) => {
+                    let metadata = package.metadata(v.as_deref());
+                    offer_candidates(
+                        package.version_candidates(),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.metadata(metadata);
+                    report.name(match package.discontinued_explanation() {
+                        Some(discontinued) => purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::ProbablyOk,
+                            explanation: format!("found on pub.dev, but {discontinued}"),
+                        },
+                        None => purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "found on pub.dev".to_string(),
+                        },
+                    });
+                    if let Some(version) = v {
+                        match package.versions.iter().find(|p| p.version == version) {
+                            Some(p) if p.retracted => report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::ProbablyOk,
+                                explanation: "found on pub.dev, but retracted".to_string(),
+                            }),
+                            Some(_) => report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on pub.dev".to_string(),
+                            }),
+                            None => report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on pub.dev".to_string(),
+                            }),
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on pub.dev".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check pub.dev ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Hackage => {
+            report.active_check(Some(CheckType::Hackage));
+
+            match purl_eval_hackage::get_preferred_versions(&n).await {
+                Ok(Some(preferred)) => {
+                    offer_candidates(preferred.version_candidates(), None);
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: "found on Hackage".to_string(),
+                    });
+                    if let Some(version) = v {
+                        if preferred.normal_version.contains(&version) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on Hackage".to_string(),
+                            });
+                        } else if preferred.deprecated_version.contains(&version) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::ProbablyOk,
+                                explanation: "found on Hackage, but deprecated".to_string(),
+                            });
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on Hackage".to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on Hackage".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check Hackage ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Cran => {
+            report.active_check(Some(CheckType::Cran));
+
+            match purl_eval_cran::get_package(&n).await {
+                Ok(Some(package)) => {
+                    let metadata = package.metadata(v.as_deref());
+                    offer_candidates(
+                        package.version_candidates(),
+                        metadata.latest_version.as_deref(),
+                    );
+                    report.metadata(metadata);
+                    report.name(if package.archived {
+                        purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::ProbablyOk,
+                            explanation: "found on CRAN, but archived (no longer maintained there)"
+                                .to_string(),
+                        }
+                    } else {
+                        purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: "found on CRAN".to_string(),
+                        }
+                    });
+                    if let Some(version) = v {
+                        if package.versions.contains_key(&version) {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: "found on CRAN".to_string(),
+                            });
+                        } else {
+                            report.version(purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::AtLeastValid,
+                                explanation: "not found on CRAN".to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(None) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: "did not find this package on CRAN".to_string(),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check CRAN ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        purl_data::PurlType::Deb | purl_data::PurlType::Apk => {
+            let format = match t {
+                purl_data::PurlType::Deb => purl_eval_index::IndexFormat::Deb,
+                _ => purl_eval_index::IndexFormat::Apk,
+            };
+            let local_entries = options
+                .local_indexes
+                .iter()
+                .filter(|(index_format, _)| *index_format == format)
+                .flat_map(|(_, entries)| entries.iter().cloned())
+                .collect::<Vec<_>>();
+            // distro versions routinely contain characters like ':' and '+'
+            let v = v.map(|v| {
+                urlencoding::decode(&v)
+                    .map(|decoded| decoded.into_owned())
+                    .unwrap_or(v)
+            });
+            let arch = purl_data::qualifier_value(&q, "arch");
+
+            let (entries, source) = if !local_entries.is_empty() {
+                (local_entries, "the local index files".to_string())
+            } else {
+                let Some(distro) = purl_data::qualifier_value(&q, "distro") else {
+                    report.warning(
+                        "add a 'distro' qualifier or load index files in the settings to check against a package index"
+                            .to_string(),
+                    );
+                    return;
+                };
+                let (check_type, mirror, result) = match format {
+                    purl_eval_index::IndexFormat::Deb => {
+                        let mirror = options
+                            .deb_mirror
+                            .clone()
+                            .unwrap_or_else(|| purl_eval_deb::default_mirror(&ns).to_string());
+                        report.active_check(Some(CheckType::DebianIndex));
+                        let result = purl_eval_deb::get_release_index(
+                            &mirror,
+                            &ns,
+                            distro,
+                            arch.unwrap_or(purl_eval_deb::DEFAULT_ARCH),
+                        )
+                        .await;
+                        (CheckType::DebianIndex, mirror, result)
+                    }
+                    purl_eval_index::IndexFormat::Apk => {
+                        let mirror = options
+                            .apk_mirror
+                            .clone()
+                            .unwrap_or_else(|| purl_eval_apk::DEFAULT_MIRROR.to_string());
+                        report.active_check(Some(CheckType::AlpineIndex));
+                        let result = purl_eval_apk::get_release_index(
+                            &mirror,
+                            distro,
+                            arch.unwrap_or(purl_eval_apk::DEFAULT_ARCH),
+                        )
+                        .await;
+                        (CheckType::AlpineIndex, mirror, result)
+                    }
+                };
+                report.active_check(None);
+                match result {
+                    Ok(Some(entries)) => (entries, format!("{mirror} ({distro})")),
+                    Ok(None) => {
+                        report.warning(format!("{mirror} has no package index for '{distro}'"));
+                        return;
+                    }
+                    Err(e) => {
+                        log::warn!("an unexpected error occurred fetching the {check_type} ({e})");
+                        report.warning(format!(
+                            "could not fetch the package index from {mirror}, the mirror may not allow cross-origin requests ({e})"
+                        ));
+                        return;
+                    }
+                }
+            };
+
+            match purl_eval_index::lookup(&entries, &n, v.as_deref(), arch) {
+                purl_eval_index::IndexLookup::NameNotFound => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!("did not find this package in {source}"),
+                    });
+                }
+                purl_eval_index::IndexLookup::VersionNotFound { available_versions } => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    report.version(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!(
+                            "not found in {source} (only {})",
+                            available_versions
+                                .iter()
+                                .map(|version| format!("'{version}'"))
+                                .join(", ")
+                        ),
+                    });
+                }
+                purl_eval_index::IndexLookup::ArchitectureNotFound {
+                    available_architectures,
+                } => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    report.version(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!(
+                            "found in {source}, but not for arch '{}' (only for {})",
+                            arch.unwrap_or_default(),
+                            available_architectures
+                                .iter()
+                                .map(|architecture| format!("'{architecture}'"))
+                                .join(", ")
+                        ),
+                    });
+                }
+                purl_eval_index::IndexLookup::Found => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    if v.is_some() {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: match arch {
+                                Some(arch) => {
+                                    format!("found in {source} for arch '{arch}'")
+                                }
+                                None => format!("found in {source}"),
+                            },
+                        });
+                    }
+                }
+            }
+        }
+        purl_data::PurlType::Conda => {
+            let local_repodata = options.local_repodata.clone();
+            let subdir =
+                purl_data::qualifier_value(&q, "subdir").unwrap_or(purl_eval_conda::DEFAULT_SUBDIR);
+            let build = purl_data::qualifier_value(&q, "build");
+            let package_type = purl_data::qualifier_value(&q, "type");
+
+            let (repodata, source) = if !local_repodata.is_empty() {
+                (local_repodata, "the local repodata files".to_string())
+            } else {
+                report.active_check(Some(CheckType::Conda));
+                let channel_url =
+                    purl_eval_conda::channel_url(purl_data::qualifier_value(&q, "channel"));
+                let result = purl_eval_conda::get_repodata(&channel_url, subdir).await;
+                report.active_check(None);
+                match result {
+                    Ok(Some(repodata)) => (
+                        vec![std::rc::Rc::new(repodata)],
+                        format!("{channel_url}/{subdir}"),
+                    ),
+                    Ok(None) => {
+                        report.warning(format!(
+                            "{channel_url} has no repodata for subdir '{subdir}'"
+                        ));
+                        return;
+                    }
+                    Err(e) => {
+                        log::warn!("an unexpected error occurred fetching conda repodata ({e})");
+                        report.warning(format!(
+                            "could not fetch the repodata from {channel_url} ({e})"
+                        ));
+                        return;
+                    }
+                }
+            };
+
+            // with several local files, the best outcome of any of them counts
+            let lookup = repodata
+                .iter()
+                .map(|repodata| repodata.lookup(&n, v.as_deref(), build, package_type))
+                .min_by_key(|lookup| match lookup {
+                    purl_eval_conda::CondaLookup::Found { .. } => 0,
+                    purl_eval_conda::CondaLookup::BuildNotFound { .. } => 1,
+                    purl_eval_conda::CondaLookup::VersionNotFound { .. } => 2,
+                    purl_eval_conda::CondaLookup::NameNotFound => 3,
+                })
+                .unwrap_or(purl_eval_conda::CondaLookup::NameNotFound);
+            match lookup {
+                purl_eval_conda::CondaLookup::NameNotFound => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!("did not find this package in {source}"),
+                    });
+                }
+                purl_eval_conda::CondaLookup::VersionNotFound { available_versions } => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    report.version(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!(
+                            "not found in {source} (only {})",
+                            available_versions
+                                .iter()
+                                .map(|version| format!("'{version}'"))
+                                .join(", ")
+                        ),
+                    });
+                }
+                purl_eval_conda::CondaLookup::BuildNotFound { available_builds } => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    report.version(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!(
+                            "found in {source}, but not with build '{}' (only {})",
+                            build.unwrap_or_default(),
+                            available_builds
+                                .iter()
+                                .map(|build| format!("'{build}'"))
+                                .join(", ")
+                        ),
+                    });
+                }
+                purl_eval_conda::CondaLookup::Found { file_name } => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found in {source}"),
+                    });
+                    if v.is_some() {
+                        report.version(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("found in {source} as {file_name}"),
+                        });
+                    }
+                }
+            }
+        }
+        purl_data::PurlType::Huggingface => {
+            report.active_check(Some(CheckType::Huggingface));
+
+            let endpoint = match purl_data::qualifier_value(&q, "repository_url") {
+                Some(repository_url) => purl_data::repository_url_as_base_url(repository_url),
+                None => options
+                    .huggingface_endpoint
+                    .clone()
+                    .unwrap_or_else(|| purl_eval_huggingface::DEFAULT_ENDPOINT.to_string()),
+            };
+
+            match purl_eval_huggingface::get_model(&endpoint, &ns, &n, v.as_deref()).await {
+                Ok(purl_eval_huggingface::HuggingfaceLookup::Found(model)) => {
+                    report.name(if model.gated.is_gated() {
+                        purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::ProbablyOk,
+                            explanation: format!(
+                                "found on {endpoint}, but gated (access has to be requested)"
+                            ),
+                        }
+                    } else {
+                        purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("found on {endpoint}"),
+                        }
+                    });
+                    if !ns.is_empty() {
+                        report.namespace(purl_eval::EvalResult {
+                            level: purl_eval::EvalResultLevel::Verified,
+                            explanation: format!("the namespace exists on {endpoint}"),
+                        });
+                    }
+                    if let Some(v) = v {
+                        report.version(if model.is_commit(&v) {
+                            purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::Verified,
+                                explanation: format!(
+                                    "found on {endpoint} as commit {}",
+                                    model.sha
+                                ),
+                            }
+                        } else {
+                            purl_eval::EvalResult {
+                                level: purl_eval::EvalResultLevel::ProbablyOk,
+                                explanation: format!(
+                                    "found on {endpoint}, but as a branch or tag rather than a commit (currently {})",
+                                    model.sha
+                                ),
+                            }
+                        });
+                    }
+                }
+                Ok(purl_eval_huggingface::HuggingfaceLookup::RevisionNotFound) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::Verified,
+                        explanation: format!("found on {endpoint}"),
+                    });
+                    report.version(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!("not found on {endpoint} as a revision"),
+                    });
+                }
+                Ok(purl_eval_huggingface::HuggingfaceLookup::ModelNotFound) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!("did not find this model on {endpoint}"),
+                    });
+                }
+                Ok(purl_eval_huggingface::HuggingfaceLookup::Inaccessible) => {
+                    report.name(purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::AtLeastValid,
+                        explanation: format!(
+                            "{endpoint} requires authentication for this model, it is private (or does not exist)"
+                        ),
+                    });
+                }
+                Err(e) => report.warning(format!("could not check {endpoint} ({e})")),
+            }
+
+            report.active_check(None);
+        }
+        _ => {}
+    }
+}
+
+/// What checking a purl online found out, on top of its offline evaluation.
+pub struct Verification {
+    pub results: purl_eval::PurlEvalResults,
+    pub warnings: Vec<String>,
+    pub metadata: Option<purl_eval::RegistryMetadata>,
+    pub suggestions: Vec<purl_suggest::NameSuggestion>,
+    /// The check that was run, if the type has one.
+    pub check: Option<CheckType>,
+}
+
+/// Collects the reports of a check, refining the offline results it starts out with.
+struct CollectingReport {
+    results: RefCell<purl_eval::PurlEvalResults>,
+    warnings: RefCell<Vec<String>>,
+    metadata: RefCell<Option<purl_eval::RegistryMetadata>>,
+    suggestions: RefCell<Vec<purl_suggest::NameSuggestion>>,
+}
+
+impl CheckReport for CollectingReport {
+    fn namespace(&self, result: purl_eval::EvalResult) {
+        self.results.borrow_mut().namespace = result;
+    }
+
+    fn name(&self, result: purl_eval::EvalResult) {
+        self.results.borrow_mut().name = result;
+    }
+
+    fn version(&self, result: purl_eval::EvalResult) {
+        self.results.borrow_mut().version = result;
+    }
+
+    fn warning(&self, warning: String) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    fn metadata(&self, metadata: purl_eval::RegistryMetadata) {
+        *self.metadata.borrow_mut() = Some(metadata);
+    }
+
+    fn version_candidates(&self, _: Vec<purl_version::VersionCandidate>) {}
+
+    fn name_suggestions(&self, suggestions: Vec<purl_suggest::NameSuggestion>) {
+        *self.suggestions.borrow_mut() = suggestions;
+    }
+
+    fn active_check(&self, _: Option<CheckType>) {}
+}
+
+/// Evaluates the purl and, if its type, namespace and name are good enough for it, checks it
+/// against its registry.
+pub async fn verify(purl: &Purl, options: &CheckOptions) -> Verification {
+    let results = purl_eval::PurlEvalResults::of(purl);
+    if !results.worth_checking_online() {
+        return Verification {
+            results,
+            warnings: vec![],
+            metadata: None,
+            suggestions: vec![],
+            check: None,
+        };
+    }
+
+    let check_type = online_check(purl, options);
+    let report = CollectingReport {
+        results: RefCell::new(results),
+        warnings: RefCell::new(vec![]),
+        metadata: RefCell::new(None),
+        suggestions: RefCell::new(vec![]),
+    };
+    check(purl, options, &report).await;
+    Verification {
+        results: report.results.into_inner(),
+        warnings: report.warnings.into_inner(),
+        metadata: report.metadata.into_inner(),
+        suggestions: report.suggestions.into_inner(),
+        check: check_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use purl_core::purl_data::Purl;
+    use purl_core::purl_eval::{EvalResult, EvalResultLevel, PurlEvalResults};
+
+    use super::{CheckReport, CollectingReport};
+
+    #[test]
+    fn test_reports_refine_offline_results() {
+        let purl = Purl::parse("pkg:cargo/serde@1.0.0").unwrap();
+        let offline = PurlEvalResults::of(&purl);
+        let report = CollectingReport {
+            results: offline.clone().into(),
+            warnings: vec![].into(),
+            metadata: None.into(),
+            suggestions: vec![].into(),
+        };
+
+        report.name(EvalResult {
+            level: EvalResultLevel::Verified,
+            explanation: "exists on crates.io".to_string(),
+        });
+        report.warning("rate limited".to_string());
+
+        let results = report.results.into_inner();
+        assert_eq!(results.name.level, EvalResultLevel::Verified);
+        assert_eq!(results.typex, offline.typex);
+        assert_eq!(results.version, offline.version);
+        assert_eq!(
+            report.warnings.into_inner(),
+            vec!["rate limited".to_string()]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::purl_eval_index::{self, IndexEntry, IndexErr};
+
+pub const DEFAULT_MIRROR: &str = "https://dl-cdn.alpinelinux.org/alpine";
+
+/// The architecture packages are looked up for when no `arch` qualifier is given.
+pub const DEFAULT_ARCH: &str = "x86_64";
+
+/// The repositories of a release to search, in order.
+pub const REPOSITORIES: &[&str] = &["main", "community"];
+
+/// Maps a `distro` qualifier value (`alpine-3.19`, `3.19`, `v3.19`, `edge`, ...) to the
+/// release branch name used on the mirrors.
+pub fn branch(distro: &str) -> String {
+    let version = distro.strip_prefix("alpine-").unwrap_or(distro);
+    if version == "edge" || version.starts_with('v') {
+        version.to_string()
+    } else {
+        // only major and minor make up the branch, i.e. 3.19.1 is on v3.19
+        format!(
+            "v{}",
+            version.split('.').take(2).collect::<Vec<_>>().join(".")
+        )
+    }
+}
+
+/// Fetches the indexes of all repositories of an Alpine release and returns their combined
+/// entries, or `None` if the mirror has none of them.
+pub async fn get_release_index(
+    mirror: &str,
+    distro: &str,
+    arch: &str,
+) -> crate::Result<Option<Vec<IndexEntry>>> {
+    let mut entries: Option<Vec<IndexEntry>> = None;
+    for repository in REPOSITORIES {
+        if let Some(repository_entries) = get_index(mirror, distro, repository, arch).await? {
+            entries
+                .get_or_insert_with(Vec::new)
+                .extend(repository_entries);
+        }
+    }
+    Ok(entries)
+}
+
+/// Fetches and parses the `APKINDEX` of one repository of an Alpine release.
+///
+/// Note that the mirror has to allow cross-origin requests for this to work in the browser;
+/// alternatively, the index files can be supplied locally.
+pub async fn get_index(
+    mirror: &str,
+    distro: &str,
+    repository: &str,
+    arch: &str,
+) -> crate::Result<Option<Vec<IndexEntry>>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "{}/{}/{repository}/{arch}/APKINDEX.tar.gz",
+        mirror.trim_end_matches('/'),
+        branch(distro)
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => {
+            let content = resp.binary().await;
+            Ok(Some(purl_eval_index::parse_index_file(&content)?.1))
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(IndexErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Extracts the `APKINDEX` file from an (already decompressed) `APKINDEX.tar.gz`, or returns
+/// `None` if the content is not a tar archive containing one.
+pub fn extract_apkindex(tar: &[u8]) -> Option<Vec<u8>> {
+    const BLOCK: usize = 512;
+    let mut offset = 0;
+    while offset + BLOCK <= tar.len() {
+        let header = &tar[offset..offset + BLOCK];
+        // the magic at offset 257 tells us this is a (ustar) tar header at all
+        if &header[257..262] != b"ustar" {
+            return None;
+        }
+        let name = String::from_utf8_lossy(&header[0..100]);
+        let name = name.trim_end_matches('\0');
+        let size = usize::from_str_radix(
+            String::from_utf8_lossy(&header[124..136])
+                .trim_matches(|c: char| c == '\0' || c == ' '),
+            8,
+        )
+        .ok()?;
+
+        let content_start = offset + BLOCK;
+        let content_end = content_start + size;
+        if name == "APKINDEX" {
+            return tar.get(content_start..content_end).map(<[u8]>::to_vec);
+        }
+        offset = content_start + size.div_ceil(BLOCK) * BLOCK;
+    }
+    None
+}
+
+/// Parses an `APKINDEX`, in which every package is a block of single-letter-keyed lines.
+pub fn parse_apkindex(content: &str) -> Vec<IndexEntry> {
+    content
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut name = None;
+            let mut version = None;
+            let mut architectures = vec![];
+            for line in block.lines() {
+                match line.split_once(':') {
+                    Some(("P", value)) => name = Some(value.to_string()),
+                    Some(("V", value)) => version = Some(value.to_string()),
+                    Some(("A", value)) => architectures = vec![value.to_string()],
+                    _ => {}
+                }
+            }
+            Some(IndexEntry {
+                name: name?,
+                version: version?,
+                architectures,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{branch, extract_apkindex, parse_apkindex};
+    use crate::purl_eval_index::IndexEntry;
+
+    const APKINDEX: &str = "C:Q1X5dT0BIE7Qn1bYQy0Vh6Ak3C3Fg=
+P:curl
+V:8.5.0-r0
+A:x86_64
+S:193475
+T:URL retrival utility and library
+L:curl
+
+C:Q1Wm6Fz3Czn+kC3ilOhJx0qXqHWQI=
+P:musl
+V:1.2.4_git20230717-r4
+A:x86_64
+";
+
+    #[test]
+    fn test_parse_apkindex() {
+        assert_eq!(
+            parse_apkindex(APKINDEX),
+            vec![
+                IndexEntry {
+                    name: "curl".to_string(),
+                    version: "8.5.0-r0".to_string(),
+                    architectures: vec!["x86_64".to_string()],
+                },
+                IndexEntry {
+                    name: "musl".to_string(),
+                    version: "1.2.4_git20230717-r4".to_string(),
+                    architectures: vec!["x86_64".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_apkindex() {
+        let mut tar = vec![];
+        for (name, content) in [(".SIGN.RSA.key", "sig"), ("APKINDEX", APKINDEX)] {
+            let mut header = [0u8; 512];
+            header[..name.len()].copy_from_slice(name.as_bytes());
+            let size = format!("{:011o}\0", content.len());
+            header[124..136].copy_from_slice(size.as_bytes());
+            header[257..262].copy_from_slice(b"ustar");
+            tar.extend_from_slice(&header);
+            tar.extend_from_slice(content.as_bytes());
+            tar.resize(tar.len().div_ceil(512) * 512, 0);
+        }
+        tar.extend_from_slice(&[0u8; 1024]);
+
+        assert_eq!(extract_apkindex(&tar), Some(APKINDEX.as_bytes().to_vec()));
+        assert_eq!(extract_apkindex(APKINDEX.as_bytes()), None);
+    }
+
+    #[test]
+    fn test_branch() {
+        assert_eq!(branch("alpine-3.19"), "v3.19");
+        assert_eq!(branch("3.19.1"), "v3.19");
+        assert_eq!(branch("v3.18"), "v3.18");
+        assert_eq!(branch("edge"), "edge");
+    }
+}
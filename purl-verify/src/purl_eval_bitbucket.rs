@@ -0,0 +1,105 @@
+/// How a purl version was resolved against a Bitbucket repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitbucketVersionMatch {
+    Tag,
+    /// The version is a (possibly abbreviated) commit SHA; holds the full SHA.
+    Commit {
+        sha: String,
+    },
+}
+
+impl std::fmt::Display for BitbucketVersionMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitbucketVersionMatch::Tag => write!(f, "tag"),
+            BitbucketVersionMatch::Commit { sha } => write!(f, "commit ({sha})"),
+        }
+    }
+}
+
+/// Looks up `version` in the given repository, first as a tag and then, if the version looks like
+/// one, as a commit SHA.
+pub async fn repo_exists_with_version(
+    workspace: &str,
+    repo_slug: &str,
+    version: &str,
+) -> crate::Result<Option<BitbucketVersionMatch>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://api.bitbucket.org/2.0/repositories/{workspace}/{repo_slug}/refs/tags/{}",
+        urlencoding::encode(version)
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => return Ok(Some(BitbucketVersionMatch::Tag)),
+        404 => {}
+        unexpected_status_code => {
+            return Err(BitbucketCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    }
+
+    if !crate::purl_eval_github::COMMIT_SHA_REGEX.is_match(version) {
+        return Ok(None);
+    }
+
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://api.bitbucket.org/2.0/repositories/{workspace}/{repo_slug}/commit/{version}"
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => {
+            let commit: BitbucketCommit = resp.json().await?;
+            Ok(Some(BitbucketVersionMatch::Commit { sha: commit.hash }))
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(BitbucketCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub async fn repo_exists(workspace: &str, repo_slug: &str) -> crate::Result<bool> {
+    let x = crate::purl_fetch::Request::get(&format!(
+        "https://api.bitbucket.org/2.0/repositories/{workspace}/{repo_slug}"
+    ))
+    .send()
+    .await?;
+    match x.status() {
+        200 => Ok(true),
+        404 => Ok(false),
+        unexpected_status_code => {
+            Err(BitbucketCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub async fn workspace_exists(workspace: &str) -> crate::Result<bool> {
+    // the workspace endpoint itself requires authentication, listing its (public) repositories
+    // does not
+    let x = crate::purl_fetch::Request::get(&format!(
+        "https://api.bitbucket.org/2.0/repositories/{workspace}?pagelen=1"
+    ))
+    .send()
+    .await?;
+    match x.status() {
+        200 => Ok(true),
+        404 => Ok(false),
+        unexpected_status_code => {
+            Err(BitbucketCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum BitbucketCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct BitbucketCommit {
+    // intentionally abbreviated
+    pub hash: String,
+    pub date: chrono::DateTime<chrono::Utc>,
+}
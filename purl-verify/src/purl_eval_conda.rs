@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+/// The channel packages are looked up in when no `channel` qualifier is given.
+pub const DEFAULT_CHANNEL_URL: &str = "https://repo.anaconda.com/pkgs/main";
+
+/// The subdir packages are looked up in when no `subdir` qualifier is given.
+pub const DEFAULT_SUBDIR: &str = "noarch";
+
+/// Resolves a `channel` qualifier value to the channel's base URL.
+///
+/// The channels of the Anaconda distribution (`main`, `r`, ...) are served from
+/// `repo.anaconda.com`, all other named channels (`conda-forge`, `bioconda`, ...) from
+/// `conda.anaconda.org`; URLs are taken as they are.
+pub fn channel_url(channel: Option<&str>) -> String {
+    match channel {
+        None => DEFAULT_CHANNEL_URL.to_string(),
+        Some(url) if url.contains("://") => url.trim_end_matches('/').to_string(),
+        Some(channel @ ("main" | "r" | "free" | "msys2")) => {
+            format!("https://repo.anaconda.com/pkgs/{channel}")
+        }
+        Some(channel) => format!("https://conda.anaconda.org/{channel}"),
+    }
+}
+
+/// Gets the `repodata.json` of one subdir (e.g. `linux-64`) of a channel.
+///
+/// Note that for big channels like `conda-forge` this is a download of several hundred MB, so
+/// supplying a local copy may be preferable.
+pub async fn get_repodata(channel_url: &str, subdir: &str) -> crate::Result<Option<CondaRepodata>> {
+    let resp = crate::purl_fetch::Request::get(&format!("{channel_url}/{subdir}/repodata.json"))
+        .send()
+        .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(CondaCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// The outcome of looking a purl up in a channel's repodata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CondaLookup {
+    NameNotFound,
+    VersionNotFound {
+        available_versions: Vec<String>,
+    },
+    BuildNotFound {
+        available_builds: Vec<String>,
+    },
+    /// Holds the file name of the (first) matching package.
+    Found {
+        file_name: String,
+    },
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct CondaRepodata {
+    // intentionally abbreviated
+    // {
+    //   "info": { "subdir": "linux-64" },
+    pub info: Option<CondaRepodataInfo>,
+    //   "packages": { "absl-py-0.4.1-py36h06a4308_0.tar.bz2": { ... } },
+    #[serde(default)]
+    pub packages: HashMap<String, CondaPackageRecord>,
+    //   "packages.conda": { "absl-py-1.4.0-py310h06a4308_0.conda": { ... } },
+    #[serde(rename = "packages.conda", default)]
+    pub packages_conda: HashMap<String, CondaPackageRecord>,
+    //   "repodata_version": 1
+    // }
+}
+
+impl CondaRepodata {
+    /// Looks up name, version and build (the latter two only where given) among the packages,
+    /// restricted to one package format (`tar.bz2` or `conda`) if `package_type` is given.
+    pub fn lookup(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        build: Option<&str>,
+        package_type: Option<&str>,
+    ) -> CondaLookup {
+        let mut with_name = self
+            .packages
+            .iter()
+            .filter(|_| matches!(package_type, None | Some("tar.bz2")))
+            .chain(
+                self.packages_conda
+                    .iter()
+                    .filter(|_| matches!(package_type, None | Some("conda"))),
+            )
+            .filter(|(_, record)| record.name == name)
+            .collect::<Vec<_>>();
+        if with_name.is_empty() {
+            return CondaLookup::NameNotFound;
+        }
+        // for stable output, as the file names come out of a map
+        with_name.sort_by_key(|(file_name, _)| *file_name);
+
+        let with_version = with_name
+            .iter()
+            .filter(|(_, record)| version.is_none() || version == Some(record.version.as_str()))
+            .collect::<Vec<_>>();
+        if with_version.is_empty() {
+            let mut available_versions = with_name
+                .iter()
+                .map(|(_, record)| record.version.clone())
+                .collect::<Vec<_>>();
+            available_versions.sort();
+            available_versions.dedup();
+            return CondaLookup::VersionNotFound { available_versions };
+        }
+
+        match with_version
+            .iter()
+            .find(|(_, record)| build.is_none() || build == Some(record.build.as_str()))
+        {
+            Some((file_name, _)) => CondaLookup::Found {
+                file_name: file_name.to_string(),
+            },
+            None => {
+                let mut available_builds = with_version
+                    .iter()
+                    .map(|(_, record)| record.build.clone())
+                    .collect::<Vec<_>>();
+                available_builds.sort();
+                available_builds.dedup();
+                CondaLookup::BuildNotFound { available_builds }
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct CondaRepodataInfo {
+    pub subdir: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct CondaPackageRecord {
+    // intentionally abbreviated
+    // {
+    //   "build": "py36h06a4308_0",
+    pub build: String,
+    //   "build_number": 0,
+    //   "depends": [ "python >=3.6,<3.7.0a0", "six" ],
+    //   "license": "Apache 2.0",
+    pub license: Option<String>,
+    //   "md5": "45fb29ee4ad4b8a2b5dc9bb8b3b6f0d1",
+    //   "name": "absl-py",
+    pub name: String,
+    //   "subdir": "linux-64",
+    //   "timestamp": 1534430587263,
+    pub timestamp: Option<u64>,
+    //   "version": "0.4.1"
+    pub version: String,
+    // }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum CondaCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{channel_url, CondaLookup, CondaRepodata};
+
+    #[test]
+    fn test_channel_url() {
+        assert_eq!(channel_url(None), "https://repo.anaconda.com/pkgs/main");
+        assert_eq!(
+            channel_url(Some("conda-forge")),
+            "https://conda.anaconda.org/conda-forge"
+        );
+        assert_eq!(
+            channel_url(Some("https://conda.example.com/internal/")),
+            "https://conda.example.com/internal"
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        let repodata: CondaRepodata = serde_json::from_str(
+            r#"{
+                "info": { "subdir": "linux-64" },
+                "packages": {
+                    "absl-py-0.4.1-py36h06a4308_0.tar.bz2": { "name": "absl-py", "version": "0.4.1", "build": "py36h06a4308_0" },
+                    "absl-py-0.4.1-py37h06a4308_0.tar.bz2": { "name": "absl-py", "version": "0.4.1", "build": "py37h06a4308_0" }
+                },
+                "packages.conda": {
+                    "absl-py-1.4.0-py310h06a4308_0.conda": { "name": "absl-py", "version": "1.4.0", "build": "py310h06a4308_0" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            repodata.lookup("numpy", None, None, None),
+            CondaLookup::NameNotFound
+        );
+        assert_eq!(
+            repodata.lookup("absl-py", Some("0.4.1"), Some("py37h06a4308_0"), None),
+            CondaLookup::Found {
+                file_name: "absl-py-0.4.1-py37h06a4308_0.tar.bz2".to_string()
+            }
+        );
+        assert_eq!(
+            repodata.lookup("absl-py", Some("0.4.1"), Some("py38h06a4308_0"), None),
+            CondaLookup::BuildNotFound {
+                available_builds: vec!["py36h06a4308_0".to_string(), "py37h06a4308_0".to_string()]
+            }
+        );
+        assert_eq!(
+            repodata.lookup("absl-py", Some("1.4.0"), None, Some("tar.bz2")),
+            CondaLookup::VersionNotFound {
+                available_versions: vec!["0.4.1".to_string()]
+            }
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+/// Gets a package with all its versions from crandb, the JSON API for CRAN metadata.
+pub async fn get_package(package_name: &str) -> crate::Result<Option<CranPackage>> {
+    let resp =
+        crate::purl_fetch::Request::get(&format!("https://crandb.r-pkg.org/{package_name}/all"))
+            .send()
+            .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(CranCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CranPackage {
+    // intentionally abbreviated
+    // {
+    //   "_id": "ggplot2",
+    //   "name": "ggplot2",
+    pub name: String,
+    //   "archived": false,
+    #[serde(default)]
+    pub archived: bool,
+    //   "latest": "3.4.4",
+    pub latest: String,
+    //   "versions": { "0.5": { "Package": "ggplot2", "Version": "0.5", "License": "GPL", ... }, ... },
+    pub versions: HashMap<String, CranVersion>,
+    //   "timeline": { "0.5": "2007-06-10T08:36:51+00:00", ... }
+    #[serde(default)]
+    pub timeline: HashMap<String, String>,
+    // }
+}
+
+impl CranPackage {
+    /// Collects what the package tells about itself and the given version of it.
+    pub fn metadata(&self, version: Option<&str>) -> purl_core::purl_eval::RegistryMetadata {
+        let described = version
+            .and_then(|version| self.versions.get(version))
+            .or_else(|| self.versions.get(&self.latest));
+        purl_core::purl_eval::RegistryMetadata {
+            source: "CRAN".to_string(),
+            latest_version: Some(self.latest.clone()),
+            license: described.and_then(|described| described.license.clone()),
+            description: described.and_then(|described| described.title.clone()),
+            published: version
+                .and_then(|version| self.timeline.get(version))
+                .and_then(|published| published.parse().ok()),
+            warnings: vec![],
+        }
+    }
+
+    pub fn version_candidates(&self) -> Vec<purl_core::purl_version::VersionCandidate> {
+        self.versions
+            .keys()
+            .map(|version| purl_core::purl_version::VersionCandidate::new(version, None))
+            .collect()
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CranVersion {
+    // intentionally abbreviated
+    #[serde(rename = "Version")]
+    pub version: String,
+    #[serde(rename = "Title")]
+    pub title: Option<String>,
+    #[serde(rename = "License")]
+    pub license: Option<String>,
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum CranCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
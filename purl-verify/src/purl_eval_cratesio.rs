@@ -0,0 +1,477 @@
+/// Gets all versions of a crate from the crates.io API, or `None` if there is no such crate.
+pub async fn get_versions(crate_name: &str) -> crate::Result<Option<Vec<CratesioVersion>>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://crates.io/api/v1/crates/{crate_name}/versions"
+    ))
+    // .header("user-agent", "purl.tools-verifier") // this doesn't work for CORS, i'll have to do some research, bit of a noob on this
+    .send()
+    .await?;
+    if resp.status() == 404 {
+        return Ok(None);
+    }
+    match resp.json::<CratesioVersionResponse>().await? {
+        CratesioVersionResponse::SuccessfulResponse { versions } => Ok(Some(versions)),
+        CratesioVersionResponse::ErrorResponse { errors } => Err(CratesioError { errors }.into()),
+    }
+}
+
+/// Searches crates.io for crates matching the given query.
+pub async fn search(query: &str) -> crate::Result<Vec<purl_core::purl_suggest::NameSuggestion>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://crates.io/api/v1/crates?q={}&per_page=20",
+        urlencoding::encode(query)
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => Ok(resp
+            .json::<CratesioSearchResponse>()
+            .await?
+            .crates
+            .into_iter()
+            .map(|found| {
+                purl_core::purl_suggest::NameSuggestion::new(None, found.name, found.description)
+            })
+            .collect()),
+        unexpected_status_code => {
+            Err(CratesioCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CratesioSearchResponse {
+    // intentionally abbreviated
+    // {
+    //   "crates": [ { "name": "serde", "description": "A generic serialization/...", ... } ],
+    pub crates: Vec<CratesioSearchCrate>,
+    //   "meta": { "total": 5083, "next_page": "?q=serde&page=2", "prev_page": null }
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CratesioSearchCrate {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// The sparse index of crates.io, which is used unless a `repository_url` qualifier points to an
+/// alternate registry's.
+pub const DEFAULT_SPARSE_INDEX_URL: &str = "https://index.crates.io";
+
+/// Gets all index records (one per published version) of a crate from a sparse index.
+///
+/// Unlike the API, the sparse index is served statically, so it is neither rate-limited nor
+/// bothered by the missing `user-agent`, and alternate registries provide it as well.
+pub async fn get_index_records(
+    index_url: &str,
+    crate_name: &str,
+) -> crate::Result<Option<Vec<CratesioIndexRecord>>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "{}/{}",
+        index_url.trim_end_matches('/'),
+        sparse_index_path(crate_name)
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => Ok(Some(parse_index_records(&resp.text().await?)?)),
+        // crates.io answers 403 for some paths that cannot exist, 410 and 451 for removed crates
+        403 | 404 | 410 | 451 => Ok(None),
+        unexpected_status_code => {
+            Err(CratesioCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Maps a `repository_url` qualifier value to the sparse index URL of the registry, accepting
+/// Cargo's `sparse+` and `registry+` notations; an index without either is taken to be a sparse
+/// one. `None` for git indexes (other than crates.io's), which cannot be looked up over HTTP.
+pub fn sparse_index_url(repository_url: Option<&str>) -> Option<String> {
+    match repository_url {
+        None
+        | Some(
+            "https://crates.io"
+            | "crates.io"
+            | "registry+https://github.com/rust-lang/crates.io-index",
+        ) => Some(DEFAULT_SPARSE_INDEX_URL.to_string()),
+        Some(url) if url.starts_with("registry+") => None,
+        Some(url) => Some(purl_core::purl_data::repository_url_as_base_url(
+            url.strip_prefix("sparse+").unwrap_or(url),
+        )),
+    }
+}
+
+/// The path of a crate's file within an index, e.g. `se/rd/serde` or `3/s/syn`.
+pub fn sparse_index_path(crate_name: &str) -> String {
+    let name = crate_name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{name}"),
+        2 => format!("2/{name}"),
+        3 => format!("3/{}/{name}", &name[..1]),
+        _ => format!("{}/{}/{name}", &name[..2], &name[2..4]),
+    }
+}
+
+/// Parses an index file, which holds one JSON record per line.
+pub fn parse_index_records(content: &str) -> Result<Vec<CratesioIndexRecord>, serde_json::Error> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect()
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CratesioIndexRecord {
+    // intentionally abbreviated
+    // {
+    //   "name": "reqwest",
+    pub name: String,
+    //   "vers": "0.11.23",
+    pub vers: String,
+    //   "deps": [ ... ],
+    //   "cksum": "37b1ae8d9ac08420c66222fb9096fc5de435c3c48542bc5336c51892cffafb41",
+    //   "features": { ... },
+    //   "yanked": false,
+    pub yanked: bool,
+    //   "links": null,
+    //   "v": 2,
+    //   "features2": { ... },
+    //   "rust_version": "1.63.0"
+    pub rust_version: Option<String>,
+    // }
+}
+
+impl From<CratesioIndexRecord> for CratesioVersion {
+    fn from(record: CratesioIndexRecord) -> Self {
+        CratesioVersion {
+            created_at: None,
+            license: None,
+            num: record.vers,
+            yanked: record.yanked,
+        }
+    }
+}
+
+/// Collects what the versions (newest first, as the API lists them) tell about the crate and the
+/// given version of it.
+pub fn metadata(
+    source: &str,
+    versions: &[CratesioVersion],
+    version: Option<&str>,
+) -> purl_core::purl_eval::RegistryMetadata {
+    let latest = versions.iter().find(|v| !v.yanked && !v.num.contains('-'));
+    let at_version = version.and_then(|version| versions.iter().find(|v| v.num == version));
+    let mut warnings = vec![];
+    if let Some(at_version) = at_version {
+        if at_version.yanked {
+            warnings.push(purl_core::purl_eval::VersionWarning::Yanked);
+        }
+        if at_version.num.contains('-') {
+            warnings.push(purl_core::purl_eval::VersionWarning::Prerelease);
+        }
+    }
+    purl_core::purl_eval::RegistryMetadata {
+        source: source.to_string(),
+        latest_version: latest.map(|latest| latest.num.clone()),
+        license: at_version.or(latest).and_then(|v| v.license.clone()),
+        description: None,
+        published: at_version.and_then(|v| v.created_at),
+        warnings,
+    }
+}
+
+pub fn version_candidates(
+    versions: &[CratesioVersion],
+) -> Vec<purl_core::purl_version::VersionCandidate> {
+    versions
+        .iter()
+        .map(|v| {
+            purl_core::purl_version::VersionCandidate::new(
+                &v.num,
+                if v.yanked {
+                    Some(purl_core::purl_eval::VersionWarning::Yanked)
+                } else if v.num.contains('-') {
+                    Some(purl_core::purl_eval::VersionWarning::Prerelease)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect()
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum CratesioCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+pub enum CratesioVersionResponse {
+    #[serde(rename(serialize = "ser_name"))]
+    SuccessfulResponse {
+        versions: Vec<CratesioVersion>,
+    },
+    ErrorResponse {
+        errors: Vec<CratesioErrorDetails>,
+    },
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct CratesioErrorDetails {
+    pub details: String,
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub struct CratesioError {
+    pub errors: Vec<CratesioErrorDetails>,
+}
+
+impl std::fmt::Display for CratesioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut errors = self
+            .errors
+            .iter()
+            .map(|error| format!("Error: {}", error.details))
+            .collect::<Vec<_>>();
+        errors.sort();
+        write!(f, "{}", errors.join("\n"))
+    }
+}
+
+#[derive(serde::Deserialize, Clone)]
+pub struct CratesioVersion {
+    //   "audit_actions": [
+    //     {
+    //       "action": "publish",
+    //       "time": "2023-12-18T20:41:19.272737+00:00",
+    //       "user": {
+    //         "avatar": "https://avatars.githubusercontent.com/u/51479?v=4",
+    //         "id": 359,
+    //         "login": "seanmonstar",
+    //         "name": "Sean McArthur",
+    //         "url": "https://github.com/seanmonstar"
+    //       }
+    //     }
+    //   ],
+
+    //   "checksum": "37b1ae8d9ac08420c66222fb9096fc5de435c3c48542bc5336c51892cffafb41",
+
+    //   "crate": "reqwest",
+    //   "crate_size": 158448,
+    //   "created_at": "2023-12-18T20:41:19.272737+00:00",
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    //   "dl_path": "/api/v1/crates/reqwest/0.11.23/download",
+    //   "downloads": 1684159,
+    //   "features": {
+    //     "__internal_proxy_sys_no_cache": [],
+    //     "__rustls": [
+    //       "hyper-rustls",
+    //       "tokio-rustls",
+    //       "rustls",
+    //       "__tls",
+    //       "rustls-pemfile"
+    //     ],
+    //     "__tls": [],
+    //     "blocking": [
+    //       "futures-util/io",
+    //       "tokio/rt-multi-thread",
+    //       "tokio/sync"
+    //     ],
+    //     "brotli": [
+    //       "async-compression",
+    //       "async-compression/brotli",
+    //       "tokio-util"
+    //     ],
+    //     "cookies": [
+    //       "cookie_crate",
+    //       "cookie_store"
+    //     ],
+    //     "default": [
+    //       "default-tls"
+    //     ],
+    //     "default-tls": [
+    //       "hyper-tls",
+    //       "native-tls-crate",
+    //       "__tls",
+    //       "tokio-native-tls"
+    //     ],
+    //     "deflate": [
+    //       "async-compression",
+    //       "async-compression/zlib",
+    //       "tokio-util"
+    //     ],
+    //     "gzip": [
+    //       "async-compression",
+    //       "async-compression/gzip",
+    //       "tokio-util"
+    //     ],
+    //     "http3": [
+    //       "rustls-tls-manual-roots",
+    //       "h3",
+    //       "h3-quinn",
+    //       "quinn",
+    //       "futures-channel"
+    //     ],
+    //     "json": [
+    //       "serde_json"
+    //     ],
+    //     "multipart": [
+    //       "mime_guess"
+    //     ],
+    //     "native-tls": [
+    //       "default-tls"
+    //     ],
+    //     "native-tls-alpn": [
+    //       "native-tls",
+    //       "native-tls-crate/alpn"
+    //     ],
+    //     "native-tls-vendored": [
+    //       "native-tls",
+    //       "native-tls-crate/vendored"
+    //     ],
+    //     "rustls-tls": [
+    //       "rustls-tls-webpki-roots"
+    //     ],
+    //     "rustls-tls-manual-roots": [
+    //       "__rustls"
+    //     ],
+    //     "rustls-tls-native-roots": [
+    //       "rustls-native-certs",
+    //       "__rustls"
+    //     ],
+    //     "rustls-tls-webpki-roots": [
+    //       "webpki-roots",
+    //       "__rustls"
+    //     ],
+    //     "socks": [
+    //       "tokio-socks"
+    //     ],
+    //     "stream": [
+    //       "tokio/fs",
+    //       "tokio-util",
+    //       "wasm-streams"
+    //     ],
+    //     "trust-dns": [
+    //       "trust-dns-resolver"
+    //     ]
+    //   },
+
+    //   "id": 990011,
+
+    //   "license": "MIT OR Apache-2.0",
+    pub license: Option<String>,
+
+    //   "links": {
+    //     "authors": "/api/v1/crates/reqwest/0.11.23/authors",
+    //     "dependencies": "/api/v1/crates/reqwest/0.11.23/dependencies",
+    //     "version_downloads": "/api/v1/crates/reqwest/0.11.23/downloads"
+    //   },
+
+    //   "num": "0.11.23",
+    pub num: String,
+
+    //   "published_by": {
+    //     "avatar": "https://avatars.githubusercontent.com/u/51479?v=4",
+    //     "id": 359,
+    //     "login": "seanmonstar",
+    //     "name": "Sean McArthur",
+    //     "url": "https://github.com/seanmonstar"
+    //   },
+
+    //   "readme_path": "/api/v1/crates/reqwest/0.11.23/readme",
+
+    //   "rust_version": "1.63.0",
+
+    //   "updated_at": "2023-12-18T20:41:19.272737+00:00",
+
+    //   "yanked": false
+    pub yanked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_index_records, sparse_index_path, sparse_index_url, CratesioIndexRecord};
+
+    use paste::paste;
+
+    macro_rules! test_path {
+        ($name:ident, $crate_name:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_sparse_index_path_ $name>]() {
+                let crate_name = $crate_name;
+                let expected = $expect;
+                let result = sparse_index_path(crate_name);
+                if result != expected {
+                    panic!("sparse_index_path({crate_name}) expects {expected} got {result}")
+                }
+            }
+            }
+        };
+    }
+
+    test_path!(one, "a", "1/a");
+    test_path!(two, "cc", "2/cc");
+    test_path!(three, "syn", "3/s/syn");
+    test_path!(four, "rand", "ra/nd/rand");
+    test_path!(long_mixed_case, "Inflector", "in/fl/inflector");
+
+    #[test]
+    fn test_sparse_index_url() {
+        assert_eq!(
+            sparse_index_url(None).as_deref(),
+            Some("https://index.crates.io")
+        );
+        assert_eq!(
+            sparse_index_url(Some("sparse+https://example.com/index/")).as_deref(),
+            Some("https://example.com/index")
+        );
+        assert_eq!(
+            sparse_index_url(Some("example.com/index")).as_deref(),
+            Some("https://example.com/index")
+        );
+        assert_eq!(
+            sparse_index_url(Some(
+                "registry+https://github.com/rust-lang/crates.io-index"
+            ))
+            .as_deref(),
+            Some("https://index.crates.io")
+        );
+        assert_eq!(
+            sparse_index_url(Some("registry+https://example.com/index.git")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_index_records() {
+        let records = parse_index_records(concat!(
+            r#"{"name":"cc","vers":"1.0.0","deps":[],"cksum":"abc","features":{},"yanked":true}"#,
+            "\n",
+            r#"{"name":"cc","vers":"1.0.1","deps":[],"cksum":"def","features":{},"yanked":false,"rust_version":"1.53"}"#,
+            "\n",
+        ))
+        .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                CratesioIndexRecord {
+                    name: "cc".to_string(),
+                    vers: "1.0.0".to_string(),
+                    yanked: true,
+                    rust_version: None,
+                },
+                CratesioIndexRecord {
+                    name: "cc".to_string(),
+                    vers: "1.0.1".to_string(),
+                    yanked: false,
+                    rust_version: Some("1.53".to_string()),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::purl_eval_index::{self, IndexEntry, IndexErr};
+
+pub const DEFAULT_DEBIAN_MIRROR: &str = "https://deb.debian.org/debian";
+pub const DEFAULT_UBUNTU_MIRROR: &str = "https://archive.ubuntu.com/ubuntu";
+
+/// The architecture binary packages are looked up for when no `arch` qualifier is given.
+pub const DEFAULT_ARCH: &str = "amd64";
+
+/// The archive components to search, in order.
+pub fn components(namespace: &str) -> &'static [&'static str] {
+    match namespace {
+        "ubuntu" => &["main", "universe", "restricted", "multiverse"],
+        _ => &["main", "contrib", "non-free", "non-free-firmware"],
+    }
+}
+
+pub fn default_mirror(namespace: &str) -> &'static str {
+    match namespace {
+        "ubuntu" => DEFAULT_UBUNTU_MIRROR,
+        _ => DEFAULT_DEBIAN_MIRROR,
+    }
+}
+
+/// Fetches the indexes of all components of a distribution release (e.g. `bookworm`) and
+/// returns their combined entries, or `None` if the mirror has none of them.
+pub async fn get_release_index(
+    mirror: &str,
+    namespace: &str,
+    distro: &str,
+    arch: &str,
+) -> crate::Result<Option<Vec<IndexEntry>>> {
+    let mut entries: Option<Vec<IndexEntry>> = None;
+    for component in components(namespace) {
+        if let Some(component_entries) = get_index(mirror, distro, component, arch).await? {
+            entries
+                .get_or_insert_with(Vec::new)
+                .extend(component_entries);
+        }
+    }
+    Ok(entries)
+}
+
+/// Fetches and parses the `Packages` index (or, for the `source` architecture, the `Sources`
+/// index) of one component of a distribution release.
+///
+/// Note that the mirror has to allow cross-origin requests for this to work in the browser, which
+/// the official ones do not; alternatively, the index files can be supplied locally.
+pub async fn get_index(
+    mirror: &str,
+    distro: &str,
+    component: &str,
+    arch: &str,
+) -> crate::Result<Option<Vec<IndexEntry>>> {
+    let mirror = mirror.trim_end_matches('/');
+    let url = match arch {
+        "source" => format!("{mirror}/dists/{distro}/{component}/source/Sources.gz"),
+        arch => format!("{mirror}/dists/{distro}/{component}/binary-{arch}/Packages.gz"),
+    };
+    let resp = crate::purl_fetch::Request::get(&url).send().await?;
+    match resp.status() {
+        200 => {
+            let content = resp.binary().await;
+            Ok(Some(purl_eval_index::parse_index_file(&content)?.1))
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(IndexErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Parses a Debian `Packages` or `Sources` file (a sequence of RFC 822-style stanzas).
+pub fn parse_packages(content: &str) -> Vec<IndexEntry> {
+    content
+        .split("\n\n")
+        .filter_map(|stanza| {
+            let mut name = None;
+            let mut version = None;
+            let mut architectures = vec![];
+            // continuation lines (starting with whitespace) belong to multi-line fields, none of
+            // which we care about
+            for line in stanza.lines().filter(|l| !l.starts_with([' ', '\t'])) {
+                match line.split_once(':') {
+                    Some(("Package", value)) => name = Some(value.trim().to_string()),
+                    Some(("Version", value)) => version = Some(value.trim().to_string()),
+                    Some(("Architecture", value)) => {
+                        architectures = value.split_whitespace().map(str::to_string).collect()
+                    }
+                    _ => {}
+                }
+            }
+            Some(IndexEntry {
+                name: name?,
+                version: version?,
+                architectures,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_packages;
+    use crate::purl_eval_index::IndexEntry;
+
+    #[test]
+    fn test_parse_packages() {
+        let packages = "Package: curl
+Version: 7.88.1-10+deb12u5
+Installed-Size: 500
+Maintainer: Alessandro Ghedini <ghedo@debian.org>
+Architecture: amd64
+Description: command line tool for transferring data with URL syntax
+ curl is a command line tool for transferring data with URL syntax, supporting
+ DICT, FILE, FTP, FTPS, GOPHER, HTTP, HTTPS, IMAP, IMAPS, LDAP, LDAPS, POP3,
+ Version: 0.0.0
+
+Package: tzdata
+Version: 2024a-0+deb12u1
+Architecture: all
+";
+        assert_eq!(
+            parse_packages(packages),
+            vec![
+                IndexEntry {
+                    name: "curl".to_string(),
+                    version: "7.88.1-10+deb12u5".to_string(),
+                    architectures: vec!["amd64".to_string()],
+                },
+                IndexEntry {
+                    name: "tzdata".to_string(),
+                    version: "2024a-0+deb12u1".to_string(),
+                    architectures: vec!["all".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sources() {
+        let sources = "Package: curl
+Binary: curl, libcurl4, libcurl4-openssl-dev
+Version: 7.88.1-10+deb12u5
+Architecture: any
+Files:
+ abc 123 curl_7.88.1-10+deb12u5.dsc
+";
+        assert_eq!(
+            parse_packages(sources),
+            vec![IndexEntry {
+                name: "curl".to_string(),
+                version: "7.88.1-10+deb12u5".to_string(),
+                architectures: vec!["any".to_string()],
+            }]
+        );
+    }
+}
@@ -0,0 +1,394 @@
+lazy_static! {
+    // full (40 hex digits) or abbreviated (at least 7 hex digits) commit SHA
+    pub(crate) static ref COMMIT_SHA_REGEX: regex::Regex = regex::Regex::new(r"^[0-9a-fA-F]{7,40}$").unwrap();
+}
+
+/// How a purl version was resolved against a GitHub repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GithubVersionMatch {
+    /// A release exists for the tag of that name.
+    Release {
+        prerelease: bool,
+        draft: bool,
+        published_at: Option<chrono::DateTime<chrono::Utc>>,
+    },
+    /// A tag of that name exists (without an associated release).
+    Tag,
+    /// The version is a (possibly abbreviated) commit SHA; holds the full SHA.
+    Commit { sha: String },
+}
+
+impl std::fmt::Display for GithubVersionMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GithubVersionMatch::Release {
+                prerelease: false, ..
+            } => write!(f, "release"),
+            GithubVersionMatch::Release {
+                prerelease: true, ..
+            } => write!(f, "pre-release"),
+            GithubVersionMatch::Tag => write!(f, "tag"),
+            GithubVersionMatch::Commit { sha } => write!(f, "commit ({sha})"),
+        }
+    }
+}
+
+/// Looks up `version` in the given repository, trying (in order) a release for the tag, the tag
+/// itself and, if the version looks like one, a commit SHA.
+///
+/// All of these are direct lookups, so there is no need to page through the (potentially very
+/// long) lists of releases or tags.
+pub async fn repo_exists_with_version(
+    user_or_org_name: &str,
+    repo_name: &str,
+    version: &str,
+    token: Option<&str>,
+) -> crate::Result<Option<GithubVersionMatch>> {
+    let encoded_version = urlencoding::encode(version);
+
+    let resp = get(&format!(
+        "https://api.github.com/repos/{user_or_org_name}/{repo_name}/releases/tags/{encoded_version}"
+    ), token)
+    .await?;
+    match resp.status() {
+        200 => {
+            let release: GithubRelease = resp.json().await?;
+            return Ok(Some(GithubVersionMatch::Release {
+                prerelease: release.prerelease,
+                draft: release.draft,
+                published_at: release.published_at,
+            }));
+        }
+        404 => {}
+        unexpected_status_code => {
+            return Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    }
+
+    let resp = get(
+        &format!(
+        "https://api.github.com/repos/{user_or_org_name}/{repo_name}/git/ref/tags/{encoded_version}"
+    ),
+        token,
+    )
+    .await?;
+    match resp.status() {
+        200 => return Ok(Some(GithubVersionMatch::Tag)),
+        404 => {}
+        unexpected_status_code => {
+            return Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    }
+
+    if !COMMIT_SHA_REGEX.is_match(version) {
+        return Ok(None);
+    }
+
+    let resp = get(
+        &format!("https://api.github.com/repos/{user_or_org_name}/{repo_name}/commits/{version}"),
+        token,
+    )
+    .await?;
+    match resp.status() {
+        200 => {
+            let commit: GithubCommit = resp.json().await?;
+            Ok(Some(GithubVersionMatch::Commit { sha: commit.sha }))
+        }
+        // 422 is what GitHub answers with for SHAs it cannot resolve ("No commit found for SHA")
+        404 | 422 => Ok(None),
+        unexpected_status_code => {
+            Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Gets the most recent releases (only the first page of them, that is).
+pub async fn get_releases(
+    user_or_org_name: &str,
+    repo_name: &str,
+    token: Option<&str>,
+) -> crate::Result<Vec<GithubRelease>> {
+    let x = get(
+        &format!(
+            "https://api.github.com/repos/{user_or_org_name}/{repo_name}/releases?per_page=100"
+        ),
+        token,
+    )
+    .await?;
+    match x.status() {
+        200 => Ok(x.json().await?),
+        404 => Ok(vec![]),
+        unexpected_status_code => {
+            Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub fn version_candidates(
+    releases: &[GithubRelease],
+) -> Vec<purl_core::purl_version::VersionCandidate> {
+    releases
+        .iter()
+        .map(|release| {
+            purl_core::purl_version::VersionCandidate::new(
+                &release.tag_name,
+                if release.draft {
+                    Some(purl_core::purl_eval::VersionWarning::Draft)
+                } else if release.prerelease {
+                    Some(purl_core::purl_eval::VersionWarning::Prerelease)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect()
+}
+
+/// Searches for repositories with a name like the given one, within the given user or org if
+/// there is one.
+pub async fn search_repos(
+    repo_name: &str,
+    user_or_org_name: Option<&str>,
+    token: Option<&str>,
+) -> crate::Result<Vec<purl_core::purl_suggest::NameSuggestion>> {
+    let query = match user_or_org_name {
+        Some(user_or_org_name) => format!("{repo_name} in:name user:{user_or_org_name}"),
+        None => format!("{repo_name} in:name"),
+    };
+    let x = get(
+        &format!(
+            "https://api.github.com/search/repositories?q={}&per_page=20",
+            urlencoding::encode(&query)
+        ),
+        token,
+    )
+    .await?;
+    match x.status() {
+        200 => Ok(x
+            .json::<GithubSearchResponse>()
+            .await?
+            .items
+            .into_iter()
+            .map(|item| {
+                purl_core::purl_suggest::NameSuggestion::new(
+                    Some(item.owner.login),
+                    item.name,
+                    item.description,
+                )
+            })
+            .collect()),
+        // the user or org does not exist
+        422 => Ok(vec![]),
+        unexpected_status_code => {
+            Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubSearchResponse {
+    // intentionally abbreviated
+    // {
+    //   "total_count": 40,
+    //   "incomplete_results": false,
+    //   "items": [
+    pub items: Vec<GithubSearchItem>,
+    //   ]
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubSearchItem {
+    // intentionally abbreviated
+    // {
+    //   "name": "dayplan",
+    pub name: String,
+    //   "full_name": "ja-he/dayplan",
+    //   "owner": { "login": "ja-he", ... },
+    pub owner: GithubOwner,
+    //   "description": "Plan your day in the terminal",
+    pub description: Option<String>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubOwner {
+    // intentionally abbreviated
+    pub login: String,
+}
+
+pub async fn get_repo(
+    user_or_org_name: &str,
+    repo_name: &str,
+    token: Option<&str>,
+) -> crate::Result<Option<GithubRepo>> {
+    let x = get(
+        &format!("https://api.github.com/repos/{user_or_org_name}/{repo_name}"),
+        token,
+    )
+    .await?;
+    match x.status() {
+        200 => Ok(Some(x.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub async fn user_or_org_exists(
+    user_or_org_name: &str,
+    token: Option<&str>,
+) -> crate::Result<bool> {
+    let x = get(
+        &format!("https://api.github.com/users/{user_or_org_name}"),
+        token,
+    )
+    .await?;
+    match x.status() {
+        200 => Ok(true),
+        404 => Ok(false),
+        unexpected_status_code => {
+            Err(GithubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Sends a GET request to the GitHub API, authenticated with the personal access token if one is
+/// given.
+///
+/// Unauthenticated requests are limited to 60 per hour, so this checks the rate limit headers and
+/// turns an exhausted limit into [`GithubCheckErr::RateLimited`] rather than a generic 403.
+async fn get(url: &str, token: Option<&str>) -> crate::Result<crate::purl_fetch::Response> {
+    let request = crate::purl_fetch::Request::get(url);
+    let request = match token {
+        Some(token) => request.header("authorization", &format!("Bearer {token}")),
+        None => request,
+    };
+    let resp = request.send().await?;
+
+    let headers = resp.headers();
+    let remaining = headers.get("x-ratelimit-remaining");
+    log::debug!(
+        "GitHub API rate limit remaining: {}",
+        remaining.as_deref().unwrap_or("unknown")
+    );
+    match resp.status() {
+        401 => Err(GithubCheckErr::BadCredentials.into()),
+        403 | 429 if remaining.as_deref() == Some("0") => {
+            let reset_at = headers
+                .get("x-ratelimit-reset")
+                .and_then(|reset| reset.parse::<i64>().ok())
+                .and_then(|reset| chrono::DateTime::from_timestamp(reset, 0));
+            Err(GithubCheckErr::RateLimited { reset_at }.into())
+        }
+        _ => Ok(resp),
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum GithubCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+    #[error("GitHub rate limit exceeded{}", .reset_at.map(|t| format!(" (resets at {})", t.format("%H:%M UTC"))).unwrap_or_default())]
+    RateLimited {
+        reset_at: Option<chrono::DateTime<chrono::Utc>>,
+    },
+    #[error("GitHub rejected the access token")]
+    BadCredentials,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubRepo {
+    // intentionally abbreviated
+    // {
+    //   "id": 724712,
+    //   "full_name": "rust-lang/rust",
+    pub full_name: String,
+    //   "description": "Empowering everyone to build reliable and efficient software.",
+    pub description: Option<String>,
+    //   "archived": false,
+    #[serde(default)]
+    pub archived: bool,
+    //   "license": { "key": "other", "name": "Other", "spdx_id": "NOASSERTION", ... },
+    pub license: Option<GithubLicense>,
+    //   "default_branch": "master",
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubLicense {
+    pub name: String,
+    pub spdx_id: Option<String>,
+}
+
+impl GithubLicense {
+    /// The SPDX ID if GitHub could determine one, the license's name otherwise.
+    pub fn identifier(&self) -> String {
+        match self.spdx_id.as_deref() {
+            Some(spdx_id) if spdx_id != "NOASSERTION" => spdx_id.to_string(),
+            _ => self.name.clone(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubRelease {
+    pub url: String,
+    pub assets_url: String,
+    pub upload_url: String,
+    pub html_url: String,
+    pub id: u64,
+    pub author: GithubReleaseAuthor,
+    pub node_id: String,
+    pub tag_name: String,
+    pub target_commitish: String,
+    pub name: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub assets: Vec<GithubReleaseAsset>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubReleaseAsset {
+    // intentionally abbreviated
+    pub name: String,
+    pub url: String,
+    pub download_count: u64,
+    pub content_type: String,
+    pub state: String,
+    pub size: u64,
+    pub browser_download_url: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubReleaseAuthor {
+    // intentionally abbreviated
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubTag {
+    pub name: String,
+    pub zipball_url: String,
+    pub tarball_url: String,
+    pub commit: Commit,
+    pub node_id: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    pub url: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GithubCommit {
+    // intentionally abbreviated
+    pub sha: String,
+    pub html_url: String,
+}
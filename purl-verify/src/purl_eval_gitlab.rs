@@ -0,0 +1,156 @@
+/// The GitLab instance that is checked against unless configured otherwise (or overridden through a
+/// `repository_url` qualifier).
+pub const DEFAULT_BASE_URL: &str = "https://gitlab.com";
+
+/// How a purl version was resolved against a GitLab project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitlabVersionMatch {
+    Tag,
+    /// The version is a (possibly abbreviated) commit SHA; holds the full SHA.
+    Commit {
+        sha: String,
+    },
+}
+
+impl std::fmt::Display for GitlabVersionMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitlabVersionMatch::Tag => write!(f, "tag"),
+            GitlabVersionMatch::Commit { sha } => write!(f, "commit ({sha})"),
+        }
+    }
+}
+
+/// Looks up `version` in the given project, first as a tag and then, if the version looks like
+/// one, as a commit SHA.
+///
+/// The namespace may consist of several (sub)group segments, e.g. `gitlab-org/security`.
+pub async fn project_exists_with_version(
+    base_url: &str,
+    namespace: &str,
+    project_name: &str,
+    version: &str,
+) -> crate::Result<Option<GitlabVersionMatch>> {
+    let project_url = project_api_url(base_url, namespace, project_name);
+
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "{project_url}/repository/tags/{}",
+        urlencoding::encode(version)
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => return Ok(Some(GitlabVersionMatch::Tag)),
+        404 => {}
+        unexpected_status_code => {
+            return Err(GitlabCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    }
+
+    if !crate::purl_eval_github::COMMIT_SHA_REGEX.is_match(version) {
+        return Ok(None);
+    }
+
+    let resp =
+        crate::purl_fetch::Request::get(&format!("{project_url}/repository/commits/{version}"))
+            .send()
+            .await?;
+    match resp.status() {
+        200 => {
+            let commit: GitlabCommit = resp.json().await?;
+            Ok(Some(GitlabVersionMatch::Commit { sha: commit.id }))
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(GitlabCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub async fn project_exists(
+    base_url: &str,
+    namespace: &str,
+    project_name: &str,
+) -> crate::Result<bool> {
+    let x = crate::purl_fetch::Request::get(&project_api_url(base_url, namespace, project_name))
+        .send()
+        .await?;
+    match x.status() {
+        200 => Ok(true),
+        // private projects are indistinguishable from nonexistent ones without authentication
+        404 => Ok(false),
+        unexpected_status_code => {
+            Err(GitlabCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Checks whether the namespace exists, either as a (possibly nested) group or as a user.
+pub async fn namespace_exists(base_url: &str, namespace: &str) -> crate::Result<bool> {
+    let api_url = api_url(base_url);
+
+    let x = crate::purl_fetch::Request::get(&format!(
+        "{api_url}/groups/{}",
+        urlencoding::encode(namespace)
+    ))
+    .send()
+    .await?;
+    match x.status() {
+        200 => return Ok(true),
+        404 => {}
+        unexpected_status_code => {
+            return Err(GitlabCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    }
+
+    // users can only own top-level namespaces
+    if namespace.contains('/') {
+        return Ok(false);
+    }
+    let x = crate::purl_fetch::Request::get(&format!(
+        "{api_url}/users?username={}",
+        urlencoding::encode(namespace)
+    ))
+    .send()
+    .await?;
+    match x.status() {
+        200 => Ok(!x.json::<Vec<GitlabUser>>().await?.is_empty()),
+        unexpected_status_code => {
+            Err(GitlabCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+fn api_url(base_url: &str) -> String {
+    format!("{}/api/v4", base_url.trim_end_matches('/'))
+}
+
+fn project_api_url(base_url: &str, namespace: &str, project_name: &str) -> String {
+    // the API addresses projects by their full path, URL-encoded as a single segment
+    format!(
+        "{}/projects/{}",
+        api_url(base_url),
+        urlencoding::encode(&format!("{namespace}/{project_name}"))
+    )
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum GitlabCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GitlabCommit {
+    // intentionally abbreviated
+    pub id: String,
+    pub short_id: String,
+    pub web_url: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GitlabUser {
+    // intentionally abbreviated
+    pub id: u64,
+    pub username: String,
+}
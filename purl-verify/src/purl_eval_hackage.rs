@@ -0,0 +1,51 @@
+/// Gets the versions of a package, split up by whether the maintainers prefer or deprecated them.
+pub async fn get_preferred_versions(
+    package_name: &str,
+) -> crate::Result<Option<HackagePreferredVersions>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://hackage.haskell.org/package/{package_name}/preferred"
+    ))
+    .header("accept", "application/json")
+    .send()
+    .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(HackageCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HackagePreferredVersions {
+    // {
+    //   "normal-version": [ "2.2.2.0", "2.2.1.0", ... ],
+    #[serde(rename = "normal-version", default)]
+    pub normal_version: Vec<String>,
+    //   "deprecated-version": [ "2.0.0.0" ]
+    #[serde(rename = "deprecated-version", default)]
+    pub deprecated_version: Vec<String>,
+    // }
+}
+
+impl HackagePreferredVersions {
+    pub fn version_candidates(&self) -> Vec<purl_core::purl_version::VersionCandidate> {
+        self.normal_version
+            .iter()
+            .map(|version| purl_core::purl_version::VersionCandidate::new(version, None))
+            .chain(self.deprecated_version.iter().map(|version| {
+                purl_core::purl_version::VersionCandidate::new(
+                    version,
+                    Some(purl_core::purl_eval::VersionWarning::Deprecated(None)),
+                )
+            }))
+            .collect()
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum HackageCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+pub async fn get_package(package_name: &str) -> crate::Result<Option<HexPackage>> {
+    let resp =
+        crate::purl_fetch::Request::get(&format!("https://hex.pm/api/packages/{package_name}"))
+            .send()
+            .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(HexCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HexPackage {
+    // intentionally abbreviated
+    // {
+    //   "name": "phoenix",
+    pub name: String,
+    //   "latest_stable_version": "1.7.10",
+    pub latest_stable_version: Option<String>,
+    //   "meta": { "description": "Peace of mind from prototype to production", "licenses": [ "MIT" ], ... },
+    pub meta: HexPackageMeta,
+    //   "releases": [ { "version": "1.7.10", "inserted_at": "2023-11-03T18:01:42.016370Z", ... }, ... ],
+    pub releases: Vec<HexRelease>,
+    //   "retirements": { "1.5.0": { "reason": "security", "message": "CVE-..." } },
+    #[serde(default)]
+    pub retirements: HashMap<String, HexRetirement>,
+    // }
+}
+
+impl HexPackage {
+    /// Collects what the package tells about itself and the given version of it.
+    pub fn metadata(&self, version: Option<&str>) -> purl_core::purl_eval::RegistryMetadata {
+        let mut warnings = vec![];
+        if let Some(retirement) = version.and_then(|version| self.retirements.get(version)) {
+            warnings.push(purl_core::purl_eval::VersionWarning::Retired(
+                retirement.to_string(),
+            ));
+        }
+        if version.is_some_and(|version| version.contains('-')) {
+            warnings.push(purl_core::purl_eval::VersionWarning::Prerelease);
+        }
+        purl_core::purl_eval::RegistryMetadata {
+            source: "Hex".to_string(),
+            latest_version: self.latest_stable_version.clone(),
+            license: Some(self.meta.licenses.join(", ")).filter(|l| !l.is_empty()),
+            description: self.meta.description.clone(),
+            published: version.and_then(|version| {
+                self.releases
+                    .iter()
+                    .find(|release| release.version == version)
+                    .and_then(|release| release.inserted_at)
+            }),
+            warnings,
+        }
+    }
+
+    pub fn version_candidates(&self) -> Vec<purl_core::purl_version::VersionCandidate> {
+        self.releases
+            .iter()
+            .map(|release| {
+                purl_core::purl_version::VersionCandidate::new(
+                    &release.version,
+                    match self.retirements.get(&release.version) {
+                        Some(retirement) => Some(purl_core::purl_eval::VersionWarning::Retired(
+                            retirement.to_string(),
+                        )),
+                        None => release
+                            .version
+                            .contains('-')
+                            .then_some(purl_core::purl_eval::VersionWarning::Prerelease),
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HexPackageMeta {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub licenses: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HexRelease {
+    pub version: String,
+    pub inserted_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HexRetirement {
+    // one of "other", "invalid", "security", "deprecated", "renamed"
+    pub reason: String,
+    pub message: Option<String>,
+}
+
+impl std::fmt::Display for HexRetirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "retired ({})", self.reason)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum HexCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
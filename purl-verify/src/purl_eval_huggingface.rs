@@ -0,0 +1,154 @@
+/// The hub that is checked against unless configured otherwise (or overridden through a
+/// `repository_url` qualifier).
+pub const DEFAULT_ENDPOINT: &str = "https://huggingface.co";
+
+/// The outcome of looking a model (at a revision) up on a hub.
+pub enum HuggingfaceLookup {
+    Found(HuggingfaceModel),
+    ModelNotFound,
+    /// The model exists, but not at the requested revision.
+    RevisionNotFound,
+    /// The hub refuses to say anything about the model without authentication, which is the case
+    /// for private models (and, on the public hub, for nonexistent ones).
+    Inaccessible,
+}
+
+/// Looks up a model, at a specific revision if one is given.
+///
+/// The namespace may be empty for the few legacy models that have none.
+pub async fn get_model(
+    endpoint: &str,
+    namespace: &str,
+    model_name: &str,
+    revision: Option<&str>,
+) -> crate::Result<HuggingfaceLookup> {
+    let model_url = model_api_url(endpoint, namespace, model_name);
+    let url = match revision {
+        Some(revision) => format!("{model_url}/revision/{}", urlencoding::encode(revision)),
+        None => model_url.clone(),
+    };
+    let resp = crate::purl_fetch::Request::get(&url).send().await?;
+    match resp.status() {
+        200 => Ok(HuggingfaceLookup::Found(resp.json().await?)),
+        401 | 403 => Ok(HuggingfaceLookup::Inaccessible),
+        404 if revision.is_some() => {
+            // a missing revision is a 404 just like a missing model, so tell them apart by
+            // looking at the model alone
+            let resp = crate::purl_fetch::Request::get(&model_url).send().await?;
+            match resp.status() {
+                200 => Ok(HuggingfaceLookup::RevisionNotFound),
+                401 | 403 => Ok(HuggingfaceLookup::Inaccessible),
+                404 => Ok(HuggingfaceLookup::ModelNotFound),
+                unexpected_status_code => {
+                    Err(HuggingfaceCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+                }
+            }
+        }
+        404 => Ok(HuggingfaceLookup::ModelNotFound),
+        unexpected_status_code => {
+            Err(HuggingfaceCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+fn model_api_url(endpoint: &str, namespace: &str, model_name: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    if namespace.is_empty() {
+        format!("{endpoint}/api/models/{model_name}")
+    } else {
+        format!("{endpoint}/api/models/{namespace}/{model_name}")
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HuggingfaceModel {
+    // intentionally abbreviated
+    // {
+    //   "_id": "621ffdc136468d709f17e709",
+    //   "id": "microsoft/deberta-v3-base",
+    pub id: String,
+    //   "modelId": "microsoft/deberta-v3-base",
+    //   "author": "microsoft",
+    //   "sha": "8ccc9b6f36199bec6961081d44eb72fb3f7353f3",
+    pub sha: String,
+    //   "lastModified": "2022-09-22T12:34:19.000Z",
+    #[serde(rename = "lastModified")]
+    pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    //   "private": false,
+    #[serde(default)]
+    pub private: bool,
+    //   "disabled": false,
+    //   "gated": false | "auto" | "manual",
+    #[serde(default)]
+    pub gated: HuggingfaceGated,
+    //   "downloads": 1627290,
+    //   "likes": 203,
+    //   "library_name": "transformers",
+    //   "tags": [ "transformers", "pytorch", "deberta-v2", "en", "license:mit" ],
+    //   "pipeline_tag": "fill-mask",
+    // }
+}
+
+impl HuggingfaceModel {
+    /// Whether the resolved commit is the one a purl `version` refers to, which may be
+    /// abbreviated and is supposed to be lowercase.
+    pub fn is_commit(&self, version: &str) -> bool {
+        version.len() >= 7 && self.sha.starts_with(&version.to_lowercase())
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum HuggingfaceGated {
+    #[default]
+    #[serde(skip)]
+    NotGated,
+    Flag(bool),
+    /// How access requests are approved, `auto` or `manual`.
+    Mode(String),
+}
+
+impl HuggingfaceGated {
+    pub fn is_gated(&self) -> bool {
+        !matches!(
+            self,
+            HuggingfaceGated::NotGated | HuggingfaceGated::Flag(false)
+        )
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum HuggingfaceCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HuggingfaceGated, HuggingfaceModel};
+
+    #[test]
+    fn test_deserialize_model() {
+        let model: HuggingfaceModel = serde_json::from_str(
+            r#"{
+                "id": "meta-llama/Llama-2-7b-hf",
+                "sha": "01c7f73d771dfac7d292323805ebc428287df4f9",
+                "lastModified": "2024-04-17T08:40:48.000Z",
+                "private": false,
+                "gated": "manual"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(model.gated, HuggingfaceGated::Mode("manual".to_string()));
+        assert!(model.gated.is_gated());
+        assert!(model.is_commit("01C7F73D771DFAC7D292323805EBC428287DF4F9"));
+        assert!(model.is_commit("01c7f73"));
+        assert!(!model.is_commit("main"));
+
+        let model: HuggingfaceModel = serde_json::from_str(
+            r#"{ "id": "microsoft/deberta-v3-base", "sha": "8ccc9b6f36199bec6961081d44eb72fb3f7353f3", "gated": false }"#,
+        )
+        .unwrap();
+        assert!(!model.gated.is_gated());
+    }
+}
@@ -0,0 +1,208 @@
+use std::io::Read;
+
+use crate::{purl_eval_apk, purl_eval_deb};
+
+/// A single package (at a single version) as listed in a distribution package index, i.e. a Debian
+/// `Packages`/`Sources` file or an Alpine `APKINDEX`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexEntry {
+    pub name: String,
+    pub version: String,
+    /// Architectures the package is built for; binary package indexes list exactly one, source
+    /// package indexes can list several (or wildcards like `any`).
+    pub architectures: Vec<String>,
+}
+
+/// The kind of index, i.e. which purl type its entries belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexFormat {
+    Deb,
+    Apk,
+}
+
+/// The outcome of looking a purl up in a package index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexLookup {
+    NameNotFound,
+    VersionNotFound {
+        available_versions: Vec<String>,
+    },
+    ArchitectureNotFound {
+        available_architectures: Vec<String>,
+    },
+    Found,
+}
+
+/// Looks up name, version and architecture (the latter two only where given) in the entries.
+pub fn lookup(
+    entries: &[IndexEntry],
+    name: &str,
+    version: Option<&str>,
+    architecture: Option<&str>,
+) -> IndexLookup {
+    let with_name = entries
+        .iter()
+        .filter(|entry| entry.name == name)
+        .collect::<Vec<_>>();
+    if with_name.is_empty() {
+        return IndexLookup::NameNotFound;
+    }
+
+    let with_version = match version {
+        Some(version) => with_name
+            .iter()
+            .filter(|entry| entry.version == version)
+            .cloned()
+            .collect::<Vec<_>>(),
+        None => with_name.clone(),
+    };
+    if with_version.is_empty() {
+        let mut available_versions = with_name
+            .iter()
+            .map(|entry| entry.version.clone())
+            .collect::<Vec<_>>();
+        available_versions.dedup();
+        return IndexLookup::VersionNotFound { available_versions };
+    }
+
+    match architecture {
+        Some(architecture)
+            if !with_version.iter().any(|entry| {
+                entry
+                    .architectures
+                    .iter()
+                    .any(|a| a == architecture || a == "all" || a == "any" || a == "noarch")
+            }) =>
+        {
+            let mut available_architectures = with_version
+                .iter()
+                .flat_map(|entry| entry.architectures.clone())
+                .collect::<Vec<_>>();
+            available_architectures.sort();
+            available_architectures.dedup();
+            IndexLookup::ArchitectureNotFound {
+                available_architectures,
+            }
+        }
+        _ => IndexLookup::Found,
+    }
+}
+
+/// Parses an index file of any supported format, (gzip-)compressed or not.
+///
+/// That is a Debian `Packages` or `Sources` file, an Alpine `APKINDEX` or an `APKINDEX.tar.gz`
+/// as found on the mirrors.
+pub fn parse_index_file(content: &[u8]) -> Result<(IndexFormat, Vec<IndexEntry>), IndexErr> {
+    let content = if content.starts_with(&[0x1f, 0x8b]) {
+        gunzip(content)?
+    } else {
+        content.to_vec()
+    };
+
+    let content = match purl_eval_apk::extract_apkindex(&content) {
+        Some(apkindex) => apkindex,
+        None => content,
+    };
+
+    let text = String::from_utf8(content).map_err(|_| IndexErr::NotUtf8)?;
+    if text.starts_with("C:") || text.starts_with("P:") {
+        Ok((IndexFormat::Apk, purl_eval_apk::parse_apkindex(&text)))
+    } else if text.starts_with("Package:") {
+        Ok((IndexFormat::Deb, purl_eval_deb::parse_packages(&text)))
+    } else {
+        Err(IndexErr::UnknownFormat)
+    }
+}
+
+/// Decompresses gzip data, including data consisting of several concatenated gzip members (which
+/// is what signed `APKINDEX.tar.gz` files are).
+pub fn gunzip(content: &[u8]) -> Result<Vec<u8>, IndexErr> {
+    let mut decompressed = vec![];
+    flate2::read::MultiGzDecoder::new(content)
+        .read_to_end(&mut decompressed)
+        .map_err(|e| IndexErr::Decompression(e.to_string()))?;
+    Ok(decompressed)
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum IndexErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+    #[error("could not decompress ({})", .0)]
+    Decompression(String),
+    #[error("index is not valid UTF-8")]
+    NotUtf8,
+    #[error("not a Debian Packages/Sources or Alpine APKINDEX file")]
+    UnknownFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::{lookup, parse_index_file, IndexEntry, IndexFormat, IndexLookup};
+
+    fn entry(name: &str, version: &str, architectures: &[&str]) -> IndexEntry {
+        IndexEntry {
+            name: name.to_string(),
+            version: version.to_string(),
+            architectures: architectures.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let entries = vec![
+            entry("curl", "7.88.1-10", &["amd64"]),
+            entry("curl", "7.88.1-10", &["i386"]),
+            entry("curl", "7.74.0-1", &["amd64"]),
+            entry("tzdata", "2024a-0", &["all"]),
+        ];
+
+        assert_eq!(
+            lookup(&entries, "wget", None, None),
+            IndexLookup::NameNotFound
+        );
+        assert_eq!(
+            lookup(&entries, "curl", Some("7.88.1-10"), Some("amd64")),
+            IndexLookup::Found
+        );
+        assert_eq!(
+            lookup(&entries, "curl", Some("7.88.1-10"), Some("arm64")),
+            IndexLookup::ArchitectureNotFound {
+                available_architectures: vec!["amd64".to_string(), "i386".to_string()]
+            }
+        );
+        assert_eq!(
+            lookup(&entries, "curl", Some("8.0.0-1"), None),
+            IndexLookup::VersionNotFound {
+                available_versions: vec!["7.88.1-10".to_string(), "7.74.0-1".to_string()]
+            }
+        );
+        assert_eq!(
+            lookup(&entries, "tzdata", Some("2024a-0"), Some("arm64")),
+            IndexLookup::Found
+        );
+    }
+
+    #[test]
+    fn test_parse_gzipped_packages() {
+        let packages = "Package: curl\nVersion: 7.88.1-10\nArchitecture: amd64\n";
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(packages.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        assert_eq!(
+            parse_index_file(&gzipped).unwrap(),
+            (
+                IndexFormat::Deb,
+                vec![entry("curl", "7.88.1-10", &["amd64"])]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert!(parse_index_file(b"{\"not\": \"an index\"}").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+/// Gets all (listed and unlisted) versions of a package from the flat container, normalized and
+/// lowercased as NuGet stores them.
+pub async fn get_versions(package_id: &str) -> crate::Result<Option<Vec<String>>> {
+    let lower_id = package_id.to_lowercase();
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://api.nuget.org/v3-flatcontainer/{lower_id}/index.json"
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => {
+            let index: NugetVersionIndex = resp.json().await?;
+            Ok(Some(index.versions))
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(NugetCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+pub fn version_candidates(versions: &[String]) -> Vec<purl_core::purl_version::VersionCandidate> {
+    versions
+        .iter()
+        .map(|version| {
+            purl_core::purl_version::VersionCandidate::new(
+                version,
+                version
+                    .contains('-')
+                    .then_some(purl_core::purl_eval::VersionWarning::Prerelease),
+            )
+        })
+        .collect()
+}
+
+/// Gets the catalog entry of a specific package version, which is where NuGet keeps the listing
+/// and deprecation status.
+pub async fn get_version_details(
+    package_id: &str,
+    version: &str,
+) -> crate::Result<Option<NugetCatalogEntry>> {
+    let lower_id = package_id.to_lowercase();
+    let normalized_version = normalize_version(version);
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://api.nuget.org/v3/registration5-semver1/{lower_id}/{normalized_version}.json"
+    ))
+    .send()
+    .await?;
+    let leaf: NugetRegistrationLeaf = match resp.status() {
+        200 => resp.json().await?,
+        404 => return Ok(None),
+        unexpected_status_code => {
+            return Err(NugetCheckErr::UnexpectedStatusCode(unexpected_status_code).into());
+        }
+    };
+
+    let resp = crate::purl_fetch::Request::get(&leaf.catalog_entry)
+        .send()
+        .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(NugetCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Normalizes a version the way NuGet does for its URLs and indices: lowercase, no build
+/// metadata, at least three and no zero fourth numeric component (`1.0` → `1.0.0`,
+/// `1.2.3.0` → `1.2.3`).
+pub fn normalize_version(version: &str) -> String {
+    let version = version.to_lowercase();
+    let version = version.split('+').next().unwrap_or_default();
+    let (release, prerelease) = match version.split_once('-') {
+        Some((release, prerelease)) => (release, Some(prerelease)),
+        None => (version, None),
+    };
+
+    let mut parts: Vec<String> = release
+        .split('.')
+        .map(|part| match part.parse::<u64>() {
+            Ok(n) => n.to_string(),
+            Err(_) => part.to_string(),
+        })
+        .collect();
+    while parts.len() < 3 {
+        parts.push("0".to_string());
+    }
+    if parts.len() == 4 && parts[3] == "0" {
+        parts.pop();
+    }
+
+    match prerelease {
+        Some(prerelease) => format!("{}-{prerelease}", parts.join(".")),
+        None => parts.join("."),
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NugetVersionIndex {
+    // { "versions": [ "1.0.0", "1.0.1", "2.0.0-beta" ] }
+    pub versions: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NugetRegistrationLeaf {
+    // intentionally abbreviated
+    // {
+    //   "@id": "https://api.nuget.org/v3/registration5-semver1/newtonsoft.json/13.0.1.json",
+    //   "catalogEntry": "https://api.nuget.org/v3/catalog0/data/2021.03.22.20.13.54/newtonsoft.json.13.0.1.json",
+    #[serde(rename = "catalogEntry")]
+    pub catalog_entry: String,
+    //   "listed": true,
+    pub listed: Option<bool>,
+    //   "packageContent": "https://api.nuget.org/v3-flatcontainer/newtonsoft.json/13.0.1/newtonsoft.json.13.0.1.nupkg",
+    //   "published": "2021-03-22T20:10:35.243+00:00",
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NugetCatalogEntry {
+    // intentionally abbreviated
+    pub id: String,
+    pub version: String,
+    pub description: Option<String>,
+    #[serde(rename = "licenseExpression")]
+    pub license_expression: Option<String>,
+    pub listed: Option<bool>,
+    pub published: Option<chrono::DateTime<chrono::Utc>>,
+    pub deprecation: Option<NugetDeprecation>,
+}
+
+impl NugetCatalogEntry {
+    /// Collects what the catalog entry tells about the package version, with the latest version
+    /// taken from the version list (see [`get_versions`]).
+    pub fn metadata(&self, versions: &[String]) -> purl_core::purl_eval::RegistryMetadata {
+        let mut warnings = vec![];
+        if let Some(deprecation) = &self.deprecation {
+            warnings.push(purl_core::purl_eval::VersionWarning::Deprecated(Some(
+                deprecation
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| deprecation.reasons.join(", ")),
+            )));
+        }
+        if self.version.contains('-') {
+            warnings.push(purl_core::purl_eval::VersionWarning::Prerelease);
+        }
+        purl_core::purl_eval::RegistryMetadata {
+            source: "NuGet".to_string(),
+            // the flat container lists versions in ascending order
+            latest_version: versions
+                .iter()
+                .rev()
+                .find(|version| !version.contains('-'))
+                .cloned(),
+            license: self.license_expression.clone(),
+            description: self.description.clone(),
+            published: self.published,
+            warnings,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NugetDeprecation {
+    // {
+    //   "reasons": [ "Legacy" ],
+    pub reasons: Vec<String>,
+    //   "message": "This package is no longer maintained",
+    pub message: Option<String>,
+    //   "alternatePackage": { "id": "Some.Other.Package", "range": "[2.0.0, )" }
+    #[serde(rename = "alternatePackage")]
+    pub alternate_package: Option<NugetAlternatePackage>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NugetAlternatePackage {
+    pub id: String,
+}
+
+impl std::fmt::Display for NugetDeprecation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "deprecated ({})", self.reasons.join(", "))?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        if let Some(alternate) = &self.alternate_package {
+            write!(f, " (alternative: {})", alternate.id)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum NugetCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_version;
+
+    use paste::paste;
+
+    macro_rules! test_normalize {
+        ($name:ident, $version:expr, $expect:expr) => {
+            paste! {
+            #[test]
+            fn [<test_normalize_ $name>]() {
+                let version = $version;
+                let expected = $expect;
+                let result = normalize_version(version);
+                if result != expected {
+                    panic!("normalize({version}) expects {expected} got {result}")
+                }
+            }
+            }
+        };
+    }
+
+    test_normalize!(full, "1.2.3", "1.2.3");
+    test_normalize!(short, "1.0", "1.0.0");
+    test_normalize!(zero_revision, "1.2.3.0", "1.2.3");
+    test_normalize!(revision, "1.2.3.4", "1.2.3.4");
+    test_normalize!(leading_zeros, "01.002.3", "1.2.3");
+    test_normalize!(prerelease, "2.0.0-Beta.1", "2.0.0-beta.1");
+    test_normalize!(metadata, "2.0.0+abc", "2.0.0");
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+/// Gets all tagged (i.e. non-dev) versions of a package, expanded from the minified `p2` format.
+pub async fn get_versions(
+    vendor: &str,
+    package_name: &str,
+) -> crate::Result<Option<Vec<PackagistVersion>>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://repo.packagist.org/p2/{vendor}/{package_name}.json"
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => {
+            let response: PackagistResponse = resp.json().await?;
+            let full_name = format!("{vendor}/{package_name}").to_lowercase();
+            let minified_versions = response.packages.get(&full_name).cloned();
+            match minified_versions {
+                Some(minified_versions) => Ok(Some(
+                    expand_minified(minified_versions)
+                        .into_iter()
+                        .map(serde_json::Value::Object)
+                        .map(serde_json::from_value)
+                        .collect::<Result<_, _>>()?,
+                )),
+                None => Ok(None),
+            }
+        }
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(PackagistCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Expands the `composer/2.0` minified format, in which every version only lists the fields that
+/// differ from the previous one (and `"__unset"` marks fields that are gone).
+pub fn expand_minified(
+    minified_versions: Vec<serde_json::Map<String, serde_json::Value>>,
+) -> Vec<serde_json::Map<String, serde_json::Value>> {
+    let mut current = serde_json::Map::new();
+    minified_versions
+        .into_iter()
+        .map(|diff| {
+            for (key, value) in diff {
+                if value == "__unset" {
+                    current.remove(&key);
+                } else {
+                    current.insert(key, value);
+                }
+            }
+            current.clone()
+        })
+        .collect()
+}
+
+#[derive(serde::Deserialize)]
+pub struct PackagistResponse {
+    // {
+    //   "packages": { "monolog/monolog": [ { ... }, { ... } ] },
+    pub packages: HashMap<String, Vec<serde_json::Map<String, serde_json::Value>>>,
+    //   "minified": "composer/2.0"
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct PackagistVersion {
+    // intentionally abbreviated
+    // {
+    //   "name": "monolog/monolog",
+    pub name: String,
+    //   "description": "Sends your logs to files, sockets, inboxes, databases and various web services",
+    pub description: Option<String>,
+    //   "version": "3.5.0",
+    pub version: String,
+    //   "version_normalized": "3.5.0.0",
+    pub version_normalized: String,
+    //   "license": [ "MIT" ],
+    pub license: Option<Vec<String>>,
+    //   "time": "2023-10-27T15:32:31+00:00",
+    pub time: Option<chrono::DateTime<chrono::Utc>>,
+    //   "abandoned": true | "replacement/package"
+    #[serde(default)]
+    pub abandoned: PackagistAbandoned,
+    // }
+}
+
+impl PackagistVersion {
+    /// Whether this is the version a purl `version` refers to, which may or may not carry the
+    /// `v` prefix that many tags have.
+    pub fn matches(&self, version: &str) -> bool {
+        self.version == version
+            || self.version.strip_prefix('v') == Some(version)
+            || version.strip_prefix('v') == Some(&self.version)
+    }
+}
+
+/// Collects what the versions (newest first, as Packagist lists them) tell about the package and
+/// the given version of it.
+pub fn metadata(
+    versions: &[PackagistVersion],
+    version: Option<&str>,
+) -> purl_core::purl_eval::RegistryMetadata {
+    // normalized versions of unstable releases carry a suffix, e.g. `3.0.0.0-RC1`
+    let latest = versions
+        .iter()
+        .find(|package_version| !package_version.version_normalized.contains('-'));
+    let at_version = version.and_then(|version| {
+        versions
+            .iter()
+            .find(|package_version| package_version.matches(version))
+    });
+    let described = at_version.or(latest);
+    purl_core::purl_eval::RegistryMetadata {
+        source: "Packagist".to_string(),
+        latest_version: latest.map(|latest| latest.version.clone()),
+        license: described
+            .and_then(|described| described.license.as_ref())
+            .map(|licenses| licenses.join(", "))
+            .filter(|licenses| !licenses.is_empty()),
+        description: described.and_then(|described| described.description.clone()),
+        published: at_version.and_then(|at_version| at_version.time),
+        warnings: match at_version {
+            Some(at_version) if at_version.version_normalized.contains('-') => {
+                vec![purl_core::purl_eval::VersionWarning::Prerelease]
+            }
+            _ => vec![],
+        },
+    }
+}
+
+pub fn version_candidates(
+    versions: &[PackagistVersion],
+) -> Vec<purl_core::purl_version::VersionCandidate> {
+    versions
+        .iter()
+        .map(|package_version| {
+            purl_core::purl_version::VersionCandidate::new(
+                &package_version.version,
+                package_version
+                    .version_normalized
+                    .contains('-')
+                    .then_some(purl_core::purl_eval::VersionWarning::Prerelease),
+            )
+        })
+        .collect()
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum PackagistAbandoned {
+    #[default]
+    #[serde(skip)]
+    NotAbandoned,
+    Flag(bool),
+    Replacement(String),
+}
+
+impl PackagistAbandoned {
+    pub fn is_abandoned(&self) -> bool {
+        !matches!(
+            self,
+            PackagistAbandoned::NotAbandoned | PackagistAbandoned::Flag(false)
+        )
+    }
+}
+
+impl std::fmt::Display for PackagistAbandoned {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackagistAbandoned::NotAbandoned | PackagistAbandoned::Flag(false) => {
+                write!(f, "not abandoned")
+            }
+            PackagistAbandoned::Flag(true) => write!(f, "abandoned"),
+            PackagistAbandoned::Replacement(replacement) => {
+                write!(f, "abandoned (use {replacement} instead)")
+            }
+        }
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum PackagistCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_minified, PackagistAbandoned, PackagistVersion};
+
+    #[test]
+    fn test_expand_minified() {
+        let minified: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_str(
+            r#"[
+                {"name": "acme/foo", "version": "2.0.0", "version_normalized": "2.0.0.0", "abandoned": "acme/bar", "license": ["MIT"]},
+                {"version": "1.0.0", "version_normalized": "1.0.0.0", "license": "__unset"}
+            ]"#,
+        )
+        .unwrap();
+
+        let versions: Vec<PackagistVersion> = expand_minified(minified)
+            .into_iter()
+            .map(|v| serde_json::from_value(serde_json::Value::Object(v)).unwrap())
+            .collect();
+
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[1].name, "acme/foo");
+        assert_eq!(versions[1].version, "1.0.0");
+        assert_eq!(versions[1].license, None);
+        assert_eq!(
+            versions[1].abandoned,
+            PackagistAbandoned::Replacement("acme/bar".to_string())
+        );
+    }
+}
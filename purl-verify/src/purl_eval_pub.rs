@@ -0,0 +1,117 @@
+pub async fn get_package(package_name: &str) -> crate::Result<Option<PubPackage>> {
+    let resp =
+        crate::purl_fetch::Request::get(&format!("https://pub.dev/api/packages/{package_name}"))
+            .send()
+            .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(PubCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PubPackage {
+    // intentionally abbreviated
+    // {
+    //   "name": "http",
+    pub name: String,
+    //   "isDiscontinued": true,
+    #[serde(rename = "isDiscontinued", default)]
+    pub is_discontinued: bool,
+    //   "replacedBy": "other_package",
+    #[serde(rename = "replacedBy")]
+    pub replaced_by: Option<String>,
+    //   "latest": { "version": "1.1.2", ... },
+    pub latest: PubVersion,
+    //   "versions": [ { "version": "0.9.2", ... }, ... ]
+    pub versions: Vec<PubVersion>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PubVersion {
+    // {
+    //   "version": "1.1.2",
+    pub version: String,
+    //   "retracted": true,
+    #[serde(default)]
+    pub retracted: bool,
+    //   "pubspec": { "name": "http", "description": "A composable, multi-platform, Future-based API for HTTP requests.", ... },
+    pub pubspec: PubPubspec,
+    //   "archive_url": "https://pub.dev/packages/http/versions/1.1.2.tar.gz",
+    //   "published": "2023-12-11T23:58:53.621183Z"
+    pub published: Option<chrono::DateTime<chrono::Utc>>,
+    // }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PubPubspec {
+    // intentionally abbreviated
+    pub description: Option<String>,
+}
+
+impl PubPackage {
+    pub fn discontinued_explanation(&self) -> Option<String> {
+        self.is_discontinued.then(|| match &self.replaced_by {
+            Some(replacement) => format!("discontinued (replaced by {replacement})"),
+            None => "discontinued".to_string(),
+        })
+    }
+
+    /// Collects what the package tells about itself and the given version of it.
+    pub fn metadata(&self, version: Option<&str>) -> purl_core::purl_eval::RegistryMetadata {
+        let at_version = version.and_then(|version| {
+            self.versions
+                .iter()
+                .find(|pub_version| pub_version.version == version)
+        });
+        let mut warnings = vec![];
+        if let Some(at_version) = at_version {
+            if at_version.retracted {
+                warnings.push(purl_core::purl_eval::VersionWarning::Retracted);
+            }
+            if at_version.version.contains('-') {
+                warnings.push(purl_core::purl_eval::VersionWarning::Prerelease);
+            }
+        }
+        purl_core::purl_eval::RegistryMetadata {
+            source: "pub.dev".to_string(),
+            latest_version: Some(self.latest.version.clone()),
+            license: None,
+            description: at_version
+                .unwrap_or(&self.latest)
+                .pubspec
+                .description
+                .clone(),
+            published: at_version.and_then(|at_version| at_version.published),
+            warnings,
+        }
+    }
+
+    pub fn version_candidates(&self) -> Vec<purl_core::purl_version::VersionCandidate> {
+        self.versions
+            .iter()
+            .map(|pub_version| {
+                purl_core::purl_version::VersionCandidate::new(
+                    &pub_version.version,
+                    if pub_version.retracted {
+                        Some(purl_core::purl_eval::VersionWarning::Retracted)
+                    } else if pub_version.version.contains('-') {
+                        Some(purl_core::purl_eval::VersionWarning::Prerelease)
+                    } else {
+                        None
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum PubCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
@@ -0,0 +1,105 @@
+/// The platform a gem is built for when no `platform` qualifier is given.
+pub const DEFAULT_PLATFORM: &str = "ruby";
+
+/// Gets all versions of a gem.
+///
+/// Yanked versions are not part of this list, RubyGems simply stops serving them.
+pub async fn get_versions(gem_name: &str) -> crate::Result<Option<Vec<RubygemsVersion>>> {
+    let resp = crate::purl_fetch::Request::get(&format!(
+        "https://rubygems.org/api/v1/versions/{gem_name}.json"
+    ))
+    .send()
+    .await?;
+    match resp.status() {
+        200 => Ok(Some(resp.json().await?)),
+        404 => Ok(None),
+        unexpected_status_code => {
+            Err(RubygemsCheckErr::UnexpectedStatusCode(unexpected_status_code).into())
+        }
+    }
+}
+
+/// Collects what the versions (newest first, as RubyGems lists them) tell about the gem and the
+/// given version of it on the given platform.
+pub fn metadata(
+    versions: &[RubygemsVersion],
+    version: Option<&str>,
+    platform: &str,
+) -> purl_core::purl_eval::RegistryMetadata {
+    let latest = versions.iter().find(|gem_version| !gem_version.prerelease);
+    let at_version = version.and_then(|version| {
+        versions
+            .iter()
+            .find(|gem_version| gem_version.number == version && gem_version.platform == platform)
+    });
+    let described = at_version.or(latest);
+    purl_core::purl_eval::RegistryMetadata {
+        source: "RubyGems".to_string(),
+        latest_version: latest.map(|latest| latest.number.clone()),
+        license: described
+            .and_then(|described| described.licenses.as_ref())
+            .map(|licenses| licenses.join(", "))
+            .filter(|licenses| !licenses.is_empty()),
+        description: described.and_then(|described| described.summary.clone()),
+        published: at_version.and_then(|at_version| at_version.created_at),
+        warnings: match at_version {
+            Some(at_version) if at_version.prerelease => {
+                vec![purl_core::purl_eval::VersionWarning::Prerelease]
+            }
+            _ => vec![],
+        },
+    }
+}
+
+/// The versions available for the given platform.
+pub fn version_candidates(
+    versions: &[RubygemsVersion],
+    platform: &str,
+) -> Vec<purl_core::purl_version::VersionCandidate> {
+    versions
+        .iter()
+        .filter(|gem_version| gem_version.platform == platform)
+        .map(|gem_version| {
+            purl_core::purl_version::VersionCandidate::new(
+                &gem_version.number,
+                gem_version
+                    .prerelease
+                    .then_some(purl_core::purl_eval::VersionWarning::Prerelease),
+            )
+        })
+        .collect()
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct RubygemsVersion {
+    // intentionally abbreviated
+    // {
+    //   "authors": "David Heinemeier Hansson",
+    //   "built_at": "2023-10-15T00:00:00.000Z",
+    //   "created_at": "2023-10-15T20:35:37.286Z",
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    //   "description": "Ruby on Rails is a full-stack web framework optimized for programmer happiness...",
+    //   "downloads_count": 1047816,
+    //   "metadata": { ... },
+    //   "number": "7.1.1",
+    pub number: String,
+    //   "summary": "Full-stack web application framework.",
+    pub summary: Option<String>,
+    //   "platform": "ruby",
+    pub platform: String,
+    //   "rubygems_version": ">= 1.8.11",
+    //   "ruby_version": ">= 2.7.0",
+    //   "prerelease": false,
+    pub prerelease: bool,
+    //   "licenses": [ "MIT" ],
+    pub licenses: Option<Vec<String>>,
+    //   "requirements": [],
+    //   "sha": "3c6d9e3e5e3ca2a9b0ed3b59b4f6b3ef7e5f7de6aee7cae6cd5d27d7f6ff0303"
+    // }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum RubygemsCheckErr {
+    #[error("unexpected status code ({})", .0)]
+    UnexpectedStatusCode(u16),
+}
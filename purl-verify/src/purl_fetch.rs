@@ -0,0 +1,238 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::purl_cache;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A response as the HTTP client hands it over, with the body read completely.
+pub struct RawResponse {
+    pub status: u16,
+    /// With lowercase names.
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// What the checks send their (GET) requests through.
+///
+/// Implementations exist for the browser ([`ReqwasmClient`], feature `web`) and for native
+/// programs ([`NativeClient`], feature `native`); others can be plugged in through
+/// [`set_client`].
+pub trait HttpClient {
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+        headers: &'a [(String, String)],
+    ) -> BoxFuture<'a, crate::Result<RawResponse>>;
+
+    /// Whether responses can be withheld by CORS, which makes a failed request mean anything
+    /// from a network error to a response the registry did not allow us to read.
+    fn enforces_cors(&self) -> bool {
+        false
+    }
+}
+
+thread_local! {
+    static CLIENT: RefCell<Option<Rc<dyn HttpClient>>> = RefCell::new(None);
+}
+
+/// Replaces the HTTP client, which otherwise is the one of the enabled backend.
+pub fn set_client(client: Rc<dyn HttpClient>) {
+    CLIENT.with(|c| *c.borrow_mut() = Some(client));
+}
+
+fn client() -> crate::Result<Rc<dyn HttpClient>> {
+    if let Some(client) = CLIENT.with(|c| c.borrow().clone()) {
+        return Ok(client);
+    }
+    // with both backends enabled (as in a build of the whole workspace), the browser's only
+    // makes sense in the browser
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    let default: Option<Rc<dyn HttpClient>> = Some(Rc::new(ReqwasmClient));
+    #[cfg(all(feature = "native", not(all(feature = "web", target_arch = "wasm32"))))]
+    let default: Option<Rc<dyn HttpClient>> = Some(Rc::new(NativeClient::default()));
+    #[cfg(not(any(all(feature = "web", target_arch = "wasm32"), feature = "native")))]
+    let default: Option<Rc<dyn HttpClient>> = None;
+
+    let client = default.ok_or(FetchErr::NoClient)?;
+    CLIENT.with(|c| *c.borrow_mut() = Some(client.clone()));
+    Ok(client)
+}
+/// Whether the HTTP client in use is subject to CORS (see [`HttpClient::enforces_cors`]).
+pub fn enforces_cors() -> bool {
+    client().is_ok_and(|client| client.enforces_cors())
+}
+
+/// A GET request that is answered from the response cache when possible.
+pub struct Request {
+    url: String,
+    headers: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn get(url: &str) -> Self {
+        Request {
+            url: url.to_string(),
+            headers: vec![],
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub async fn send(self) -> crate::Result<Response> {
+        let key = purl_cache::cache_key(&self.url, &self.headers);
+        if let Some(entry) = purl_cache::lookup(&key) {
+            log::debug!("answering request for '{}' from cache", self.url);
+            return Ok(Response { entry });
+        }
+
+        let resp = client()?.get(&self.url, &self.headers).await?;
+        let entry = purl_cache::CacheEntry {
+            status: resp.status,
+            headers: resp.headers,
+            body: Rc::new(resp.body),
+            fetched_at: chrono::Utc::now(),
+            authorized: self
+                .headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case("authorization")),
+        };
+        purl_cache::store(&key, entry.clone());
+        Ok(Response { entry })
+    }
+}
+
+pub struct Response {
+    entry: purl_cache::CacheEntry,
+}
+
+impl Response {
+    pub fn status(&self) -> u16 {
+        self.entry.status
+    }
+
+    pub fn headers(&self) -> ResponseHeaders<'_> {
+        ResponseHeaders(&self.entry.headers)
+    }
+
+    pub async fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.entry.body)
+    }
+
+    pub async fn text(&self) -> Result<String, std::string::FromUtf8Error> {
+        String::from_utf8(self.entry.body.to_vec())
+    }
+
+    pub async fn binary(&self) -> Vec<u8> {
+        self.entry.body.to_vec()
+    }
+}
+
+pub struct ResponseHeaders<'a>(&'a HashMap<String, String>);
+
+impl ResponseHeaders<'_> {
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.0.get(&name.to_lowercase()).cloned()
+    }
+}
+
+/// Sends requests through the browser's fetch API.
+#[cfg(feature = "web")]
+pub struct ReqwasmClient;
+
+#[cfg(feature = "web")]
+impl HttpClient for ReqwasmClient {
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+        headers: &'a [(String, String)],
+    ) -> BoxFuture<'a, crate::Result<RawResponse>> {
+        Box::pin(async move {
+            let mut request = reqwasm::http::Request::get(url);
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            let resp = request.send().await?;
+            Ok(RawResponse {
+                status: resp.status(),
+                headers: resp
+                    .headers()
+                    .entries()
+                    .map(|(name, value)| (name.to_lowercase(), value))
+                    .collect(),
+                body: resp.binary().await?,
+            })
+        })
+    }
+
+    fn enforces_cors(&self) -> bool {
+        true
+    }
+}
+
+/// Sends blocking requests, so the futures of the checks complete on their first poll.
+#[cfg(feature = "native")]
+pub struct NativeClient {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "native")]
+impl Default for NativeClient {
+    fn default() -> Self {
+        NativeClient {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("purl-verify/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl HttpClient for NativeClient {
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+        headers: &'a [(String, String)],
+    ) -> BoxFuture<'a, crate::Result<RawResponse>> {
+        Box::pin(async move {
+            let mut request = self.agent.get(url);
+            for (name, value) in headers {
+                request = request.set(name, value);
+            }
+            let resp = match request.call() {
+                Ok(resp) => resp,
+                // statuses are for the checks to interpret
+                Err(ureq::Error::Status(_, resp)) => resp,
+                Err(e) => return Err(e.into()),
+            };
+            let status = resp.status();
+            let headers = resp
+                .headers_names()
+                .into_iter()
+                .filter_map(|name| {
+                    let value = resp.header(&name)?.to_string();
+                    Some((name.to_lowercase(), value))
+                })
+                .collect();
+            let mut body = vec![];
+            std::io::Read::read_to_end(&mut resp.into_reader(), &mut body)?;
+            Ok(RawResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum FetchErr {
+    #[error("no HTTP client available (enable the 'web' or 'native' feature or set one)")]
+    NoClient,
+}
@@ -1,37 +1,94 @@
 use itertools::Itertools;
 use leptos::*;
+use leptos_use::storage::{use_local_storage, StringCodec};
+
+use purl_core::{
+    purl_convert, purl_data, purl_eval, purl_snippet, purl_suggest, purl_url, purl_version,
+};
+use purl_verify::purl_check::{self, CheckType};
+use purl_verify::{
+    purl_cache, purl_eval_apk, purl_eval_conda, purl_eval_cratesio, purl_eval_deb,
+    purl_eval_gitlab, purl_eval_huggingface, purl_eval_index,
+};
 
 use crate::purl_data::PurlComponent;
 
-mod purl_data;
+mod purl_bulk;
+mod purl_generation;
 
-#[macro_use]
-extern crate lazy_static;
-mod purl_eval;
-mod purl_eval_cratesio;
-mod purl_eval_github;
-mod purl_eval_npm;
+/// What identifies the package that version candidates and name suggestions are for.
+type PackageKey = (purl_data::PurlType, String, String);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum CheckType {
-    CratesIo,
-    Github,
-    Npm,
+/// Passes what a check finds out on to the signals shown in the UI, as long as the check is for
+/// the latest input.
+struct UiReport {
+    generation: purl_generation::Generation,
+    key: PackageKey,
+    set_eval_namespace: WriteSignal<purl_eval::EvalResult>,
+    set_eval_name: WriteSignal<purl_eval::EvalResult>,
+    set_eval_version: WriteSignal<purl_eval::EvalResult>,
+    set_check_warning: WriteSignal<Option<String>>,
+    set_registry_metadata: WriteSignal<Option<purl_eval::RegistryMetadata>>,
+    set_version_candidates: WriteSignal<Option<(PackageKey, Vec<purl_version::VersionCandidate>)>>,
+    set_name_suggestions: WriteSignal<Option<(PackageKey, Vec<purl_suggest::NameSuggestion>)>>,
+    set_active_expensive_check: WriteSignal<Option<CheckType>>,
 }
 
-impl std::fmt::Display for CheckType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CheckType::CratesIo => write!(f, "crates.io/api/v1"),
-            CheckType::Github => write!(f, "api.github.com"),
-            CheckType::Npm => write!(f, "registry.npmjs.org"),
-        }
+impl purl_check::CheckReport for UiReport {
+    fn namespace(&self, result: purl_eval::EvalResult) {
+        self.generation.guard(self.set_eval_namespace)(result)
+    }
+
+    fn name(&self, result: purl_eval::EvalResult) {
+        self.generation.guard(self.set_eval_name)(result)
+    }
+
+    fn version(&self, result: purl_eval::EvalResult) {
+        self.generation.guard(self.set_eval_version)(result)
+    }
+
+    fn warning(&self, warning: String) {
+        self.generation.guard(self.set_check_warning)(Some(warning))
+    }
+
+    fn metadata(&self, metadata: purl_eval::RegistryMetadata) {
+        self.generation.guard(self.set_registry_metadata)(Some(metadata))
+    }
+
+    fn version_candidates(&self, candidates: Vec<purl_version::VersionCandidate>) {
+        self.generation.guard(self.set_version_candidates)(Some((self.key.clone(), candidates)))
+    }
+
+    fn name_suggestions(&self, suggestions: Vec<purl_suggest::NameSuggestion>) {
+        self.generation.guard(self.set_name_suggestions)(Some((self.key.clone(), suggestions)))
+    }
+
+    fn active_check(&self, check: Option<CheckType>) {
+        self.generation.guard(self.set_active_expensive_check)(check)
     }
 }
 
+/// Index files supplied by the user (by file name), which take precedence over fetching from a
+/// mirror.
+type LocalIndexes = Vec<(
+    String,
+    purl_eval_index::IndexFormat,
+    std::rc::Rc<Vec<purl_eval_index::IndexEntry>>,
+)>;
+type LocalRepodata = Vec<(String, std::rc::Rc<purl_eval_conda::CondaRepodata>)>;
+type LocalCrateRecords = Vec<(
+    String,
+    std::rc::Rc<Vec<purl_eval_cratesio::CratesioIndexRecord>>,
+)>;
+
 #[component]
 fn App() -> impl IntoView {
     let (light_theme, set_light_theme) = create_signal(true);
+    let (bulk, set_bulk) = create_signal(false);
+    // loaded in the builder, used by the bulk checks as well
+    let (local_indexes, set_local_indexes) = create_signal::<LocalIndexes>(vec![]);
+    let (local_repodata, set_local_repodata) = create_signal::<LocalRepodata>(vec![]);
+    let (local_crate_records, set_local_crate_records) = create_signal::<LocalCrateRecords>(vec![]);
     leptos_meta::provide_meta_context();
 
     view! {
@@ -64,8 +121,33 @@ fn App() -> impl IntoView {
                     </button>
                 </div>
             </div>
-            <div id="main-content">
-                <MainContent/>
+            <div id="main-area">
+                <div id="tabs">
+                    <button class:active-tab=move || !bulk() on:click=move |_| set_bulk(false)>
+                        "builder"
+                    </button>
+                    <button class:active-tab=bulk on:click=move |_| set_bulk(true)>
+                        "bulk"
+                    </button>
+                </div>
+                // both stay mounted, so switching tabs keeps what was entered
+                <div id="main-content" style:display=move || bulk().then_some("none")>
+                    <MainContent
+                        local_indexes=local_indexes
+                        set_local_indexes=set_local_indexes
+                        local_repodata=local_repodata
+                        set_local_repodata=set_local_repodata
+                        local_crate_records=local_crate_records
+                        set_local_crate_records=set_local_crate_records
+                    />
+                </div>
+                <div style:display=move || (!bulk()).then_some("none")>
+                    <purl_bulk::BulkContent
+                        local_indexes=local_indexes
+                        local_repodata=local_repodata
+                        local_crate_records=local_crate_records
+                    />
+                </div>
             </div>
             <div id="footer">"Created by " <a href="https://hensel.dev">"Jan Hensel"</a> "."</div>
         </div>
@@ -79,7 +161,14 @@ enum InputOption {
 }
 
 #[component]
-fn MainContent() -> impl IntoView {
+fn MainContent(
+    local_indexes: ReadSignal<LocalIndexes>,
+    set_local_indexes: WriteSignal<LocalIndexes>,
+    local_repodata: ReadSignal<LocalRepodata>,
+    set_local_repodata: WriteSignal<LocalRepodata>,
+    local_crate_records: ReadSignal<LocalCrateRecords>,
+    set_local_crate_records: WriteSignal<LocalCrateRecords>,
+) -> impl IntoView {
     // abtract: scheme:type/namespace/name@version?qualifiers#subpath
     // eg.:     pkg:github/package-url/purl-spec@244fd47e07d1004f0aed9c
     let (typex, set_typex) = create_signal(purl_data::PurlType::Github);
@@ -89,6 +178,28 @@ fn MainContent() -> impl IntoView {
     let (qualifiers, set_qualifiers) = create_signal(None);
     let (subpath, set_subpath) = create_signal(None);
 
+    // what the last text pasted into the convert field was read as, if anything
+    let (conversion, set_conversion) = create_signal(None::<Option<purl_convert::Notation>>);
+    let convert_input = move |ev| {
+        let input = event_target_value(&ev);
+        if input.trim().is_empty() {
+            set_conversion(None);
+            return;
+        }
+        match purl_convert::convert(&input) {
+            Some((notation, purl)) => {
+                set_typex(purl.typex);
+                set_namespace(purl.namespace);
+                set_name(purl.name);
+                set_version(purl.version);
+                set_qualifiers(purl.qualifiers);
+                set_subpath(purl.subpath);
+                set_conversion(Some(Some(notation)));
+            }
+            None => set_conversion(Some(None)),
+        }
+    };
+
     let (type_input_option, set_type_input_option) = create_signal(InputOption::Select);
     let get_type_input_field = move || match type_input_option.get() {
         InputOption::Select => view! {
@@ -296,12 +407,13 @@ fn MainContent() -> impl IntoView {
     let full_purl_debounced = leptos_use::signal_debounced(
         Signal::derive(move || {
             with!(
-                |typex, namespace, name, version, all_at_least_probably_ok| {
+                |typex, namespace, name, version, qualifiers, all_at_least_probably_ok| {
                     (
                         typex.clone(),
                         namespace.join("/").clone(),
                         name.clone(),
                         version.clone(),
+                        qualifiers.clone(),
                         *all_at_least_probably_ok,
                     )
                 }
@@ -311,164 +423,131 @@ fn MainContent() -> impl IntoView {
     );
     let (active_expensive_check, set_active_expensive_check) =
         create_signal::<Option<CheckType>>(None);
+    let (check_warning, set_check_warning) = create_signal::<Option<String>>(None);
+    // what the registry check found out about the package beyond its existence
+    let (registry_metadata, set_registry_metadata) =
+        create_signal::<Option<purl_eval::RegistryMetadata>>(None);
+    let (github_token, set_github_token, _) =
+        use_local_storage::<String, StringCodec>("github-token");
+    let (gitlab_base_url, set_gitlab_base_url, _) =
+        use_local_storage::<String, StringCodec>("gitlab-base-url");
+    let (huggingface_endpoint, set_huggingface_endpoint, _) =
+        use_local_storage::<String, StringCodec>("huggingface-endpoint");
+    let (deb_mirror, set_deb_mirror, _) = use_local_storage::<String, StringCodec>("deb-mirror");
+    let (apk_mirror, set_apk_mirror, _) = use_local_storage::<String, StringCodec>("apk-mirror");
+    let (cratesio_backend, set_cratesio_backend, _) =
+        use_local_storage::<String, StringCodec>("cratesio-backend");
+    let (cache_ttl_minutes, set_cache_ttl_minutes, _) =
+        use_local_storage::<String, StringCodec>("cache-ttl-minutes");
+    let (cache_negative_ttl_minutes, set_cache_negative_ttl_minutes, _) =
+        use_local_storage::<String, StringCodec>("cache-negative-ttl-minutes");
     create_effect(move |_| {
-        let (t, ns, n, v, ok) = full_purl_debounced();
+        let default = purl_cache::CachePolicy::default();
+        let minutes = |setting: String| setting.parse().ok().map(chrono::Duration::minutes);
+        purl_cache::set_policy(purl_cache::CachePolicy {
+            ttl: minutes(cache_ttl_minutes()).unwrap_or(default.ttl),
+            negative_ttl: minutes(cache_negative_ttl_minutes()).unwrap_or(default.negative_ttl),
+        });
+    });
+    // the versions the registry knows for a package, keyed by what identifies the package so
+    // they are kept while only the version is edited
+    let (version_candidates, set_version_candidates) =
+        create_signal::<Option<(PackageKey, Vec<purl_version::VersionCandidate>)>>(None);
+    // close matches for a name that was not found, keyed like the version candidates
+    let (name_suggestions, set_name_suggestions) =
+        create_signal::<Option<(PackageKey, Vec<purl_suggest::NameSuggestion>)>>(None);
+    let (offline_mode, set_offline_mode, _) =
+        use_local_storage::<String, StringCodec>("offline-mode");
+    // comma-separated ids of the checks that must not go online
+    let (disabled_checks, set_disabled_checks, _) =
+        use_local_storage::<String, StringCodec>("disabled-checks");
+    let online_check_enabled = move |check_type: &CheckType| {
+        offline_mode.get() != "true"
+            && !disabled_checks
+                .get()
+                .split(',')
+                .any(|id| id == check_type.id())
+    };
+    // checks run concurrently, so only the one for the latest input may report its results
+    let check_generations = purl_generation::Generations::new();
+    create_effect(move |_| {
+        let (t, ns, n, v, q, ok) = full_purl_debounced();
+        let generation = check_generations.start();
+        // a stale check will not get to clear its spinner
+        set_active_expensive_check(None);
+        set_check_warning(None);
+        set_registry_metadata(None);
         if !ok {
             return;
         }
-
-        match t {
-            purl_data::PurlType::Cargo => spawn_local(async move {
-                set_active_expensive_check(Some(CheckType::CratesIo));
-                if let Ok(versions) = purl_eval_cratesio::get_versions(&n).await {
-                    set_eval_name(purl_eval::EvalResult {
-                        level: purl_eval::EvalResultLevel::Verified,
-                        explanation: "exists on crates.io".to_string(),
-                    });
-                    if let Some(v) = v {
-                        if versions.contains(&v) {
-                            set_eval_version(purl_eval::EvalResult {
-                                level: purl_eval::EvalResultLevel::Verified,
-                                explanation: "exists on crates.io".to_string(),
-                            })
-                        } else {
-                            set_eval_version(purl_eval::EvalResult {
-                                level: purl_eval::EvalResultLevel::AtLeastValid,
-                                explanation: "not found on crates.io".to_string(),
-                            })
-                        }
-                    }
-                } else {
-                    set_eval_name(purl_eval::EvalResult {
-                        level: purl_eval::EvalResultLevel::AtLeastValid,
-                        explanation: "not found on crates.io".to_string(),
-                    });
-                }
-                set_active_expensive_check(None);
+        let purl = purl_data::Purl {
+            typex: t.clone(),
+            namespace: purl_data::PurlNamespace::new_naive(&ns),
+            name: n.clone(),
+            version: v.clone(),
+            qualifiers: q,
+            subpath: None,
+        };
+        let options = purl_check::CheckOptions {
+            github_token: Some(github_token.get_untracked()).filter(|t| !t.is_empty()),
+            gitlab_base_url: Some(gitlab_base_url.get_untracked()).filter(|url| !url.is_empty()),
+            huggingface_endpoint: Some(huggingface_endpoint.get_untracked())
+                .filter(|url| !url.is_empty()),
+            deb_mirror: Some(deb_mirror.get_untracked()).filter(|mirror| !mirror.is_empty()),
+            apk_mirror: Some(apk_mirror.get_untracked()).filter(|mirror| !mirror.is_empty()),
+            cratesio_sparse_index: cratesio_backend.get_untracked() == "sparse",
+            local_indexes: local_indexes.with(|indexes| {
+                indexes
+                    .iter()
+                    .map(|(_, format, entries)| (*format, entries.clone()))
+                    .collect()
             }),
+            local_repodata: local_repodata
+                .with(|files| files.iter().map(|(_, repodata)| repodata.clone()).collect()),
+            local_crate_records: local_crate_records
+                .with(|files| files.iter().map(|(_, records)| records.clone()).collect()),
+        };
 
-            purl_data::PurlType::Github => spawn_local(async move {
-                set_active_expensive_check(Some(CheckType::Github));
-
-                let mut found_version = false;
-                if let Some(v) = v {
-                    match purl_eval_github::repo_exists_with_version(&ns, &n, &v).await {
-                        Ok(found) => {
-                            found_version = found;
-                            if found {
-                                set_eval_version(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::Verified,
-                                    explanation: "the version (release tag) exists on GitHub"
-                                        .to_string(),
-                                });
-                                set_eval_name(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::Verified,
-                                    explanation: "the repository exists on GitHub".to_string(),
-                                });
-                                set_eval_namespace(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::Verified,
-                                    explanation: "the namespace exists on GitHub as a user or org"
-                                        .to_string(),
-                                });
-                            } else {
-                                set_eval_version(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::AtLeastValid,
-                                    explanation:
-                                        "the version (release tag) does not exist on GitHub"
-                                            .to_string(),
-                                });
-                            }
-                        }
-                        Err(e) => log::warn!(
-                            "an unexpected error occurred checking for a GitHub repository ({e})"
-                        ),
-                    }
-                }
-
-                if !found_version {
-                    match purl_eval_github::repo_exists(&ns, &n).await {
-                        Ok(true) => {
-                            set_eval_name(purl_eval::EvalResult {
-                                level: purl_eval::EvalResultLevel::Verified,
-                                explanation: "the repository exists on GitHub".to_string(),
-                            });
-                            set_eval_namespace(purl_eval::EvalResult {
-                                level: purl_eval::EvalResultLevel::Verified,
-                                explanation: "the namespace exists on GitHub as a user or org"
-                                    .to_string(),
-                            });
-                        }
-                        Ok(false) => {
-                            set_eval_name(purl_eval::EvalResult {
-                                level: purl_eval::EvalResultLevel::AtLeastValid,
-                                explanation: "did not find the repository on GitHub".to_string(),
-                            });
-                            match purl_eval_github::user_or_org_exists(&ns).await {
-                                Ok(true) => set_eval_namespace(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::Verified,
-                                    explanation: "the namespace exists on GitHub as a user or org"
-                                        .to_string(),
-                                }),
-                                Ok(false) => set_eval_namespace(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::AtLeastValid,
-                                    explanation: "did not find this as a user or org on GitHub"
-                                        .to_string(),
-                                }),
-                                Err(e) => log::warn!("an unexpected error occurred checking for a GitHub repository ({e})"),
-                            }
-                        }
-                        Err(e) => log::warn!(
-                            "an unexpected error occurred checking for a GitHub repository ({e})"
-                        ),
-                    }
-                }
-
-                set_active_expensive_check(None);
-            }),
-            purl_data::PurlType::Npm => spawn_local(async move {
-                set_active_expensive_check(Some(CheckType::Npm));
-
-                match purl_eval_npm::get_package(&n).await {
-                    Ok(Some(package)) => {
-                        set_eval_name(purl_eval::EvalResult {
-                            level: purl_eval::EvalResultLevel::Verified,
-                            explanation: "found on NPM".to_string(),
-                        });
-                        if let Some(version) = v {
-                            if package
-                                .versions
-                                .iter()
-                                .any(|(version_as_key, _)| *version_as_key == version)
-                            {
-                                set_eval_version(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::Verified,
-                                    explanation: "found on NPM".to_string(),
-                                });
-                            } else {
-                                set_eval_version(purl_eval::EvalResult {
-                                    level: purl_eval::EvalResultLevel::AtLeastValid,
-                                    explanation: "not found on NPM".to_string(),
-                                });
-                            }
-                        }
-                    }
-                    Ok(None) => {
-                        set_eval_name(purl_eval::EvalResult {
-                            level: purl_eval::EvalResultLevel::AtLeastValid,
-                            explanation: "did not find this package on NPM".to_string(),
-                        });
-                    }
-                    Err(e) => {
-                        log::warn!(
-                            "an unexpected error occurred checking for an NPM project ({e})"
-                        );
+        if let Some(check_type) =
+            purl_check::online_check(&purl, &options).filter(|c| !online_check_enabled(c))
+        {
+            log::debug!("not checking against {check_type}, as online checks are disabled for it");
+            let not_verified = |current: purl_eval::EvalResult| {
+                if current
+                    .level
+                    .at_least_as_good_as(&purl_eval::EvalResultLevel::ProbablyOk)
+                {
+                    purl_eval::EvalResult {
+                        level: purl_eval::EvalResultLevel::ProbablyOk,
+                        explanation: "not verified (online checks disabled)".to_string(),
                     }
+                } else {
+                    current
                 }
-
-                set_active_expensive_check(None);
-            }),
-            _ => {}
+            };
+            set_eval_name(not_verified(eval_name.get_untracked()));
+            if v.is_some() {
+                set_eval_version(not_verified(eval_version.get_untracked()));
+            }
+            if purl_check::checks_namespace(&t) {
+                set_eval_namespace(not_verified(eval_namespace.get_untracked()));
+            }
+            return;
         }
+
+        let report = UiReport {
+            generation,
+            key: (t, ns, n),
+            set_eval_namespace,
+            set_eval_name,
+            set_eval_version,
+            set_check_warning,
+            set_registry_metadata,
+            set_version_candidates,
+            set_name_suggestions,
+            set_active_expensive_check,
+        };
+        spawn_local(async move { purl_check::check(&purl, &options, &report).await });
     });
 
     let get_type_explanation_box_class =
@@ -488,8 +567,115 @@ fn MainContent() -> impl IntoView {
     let get_subpath_explanation_box_class =
         move || format!("explanation-box {result}", result = eval_subpath_result());
 
+    // loads (compressed) package index files, which then take precedence over online checks
+    let load_index_files = move |ev: ev::Event| {
+        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+        let Some(file_list) = input.files() else {
+            return;
+        };
+        let files = (0..file_list.length())
+            .filter_map(|i| file_list.get(i))
+            // when loading a whole directory (like a checkout of an index repository), skip what is
+            // evidently not an index file
+            .filter(|file| {
+                file.name() != "config.json"
+                    && !js_sys::Reflect::get(file, &"webkitRelativePath".into())
+                        .ok()
+                        .and_then(|path| path.as_string())
+                        .unwrap_or_default()
+                        .split('/')
+                        .any(|segment| segment.starts_with('.'))
+            })
+            .collect::<Vec<_>>();
+        spawn_local(async move {
+            let mut loaded = vec![];
+            let mut loaded_repodata = vec![];
+            let mut loaded_crate_records = vec![];
+            for file in files {
+                let content = match wasm_bindgen_futures::JsFuture::from(file.array_buffer()).await
+                {
+                    Ok(buffer) => js_sys::Uint8Array::new(&buffer).to_vec(),
+                    Err(e) => {
+                        log::warn!("could not read '{}' ({e:?})", file.name());
+                        continue;
+                    }
+                };
+                let content = if content.starts_with(&[0x1f, 0x8b]) {
+                    match purl_eval_index::gunzip(&content) {
+                        Ok(content) => content,
+                        Err(e) => {
+                            set_check_warning(Some(format!(
+                                "could not load '{}': {e}",
+                                file.name()
+                            )));
+                            continue;
+                        }
+                    }
+                } else {
+                    content
+                };
+                if content.starts_with(b"{") {
+                    if let Some(records) = std::str::from_utf8(&content)
+                        .ok()
+                        .and_then(|text| purl_eval_cratesio::parse_index_records(text).ok())
+                    {
+                        loaded_crate_records.push((file.name(), std::rc::Rc::new(records)));
+                        continue;
+                    }
+                    match serde_json::from_slice(&content) {
+                        Ok(repodata) => {
+                            loaded_repodata.push((file.name(), std::rc::Rc::new(repodata)))
+                        }
+                        Err(e) => set_check_warning(Some(format!(
+                            "could not load '{}': {e}",
+                            file.name()
+                        ))),
+                    }
+                    continue;
+                }
+                match purl_eval_index::parse_index_file(&content) {
+                    Ok((format, entries)) => {
+                        loaded.push((file.name(), format, std::rc::Rc::new(entries)))
+                    }
+                    Err(e) => {
+                        set_check_warning(Some(format!("could not load '{}': {e}", file.name())))
+                    }
+                }
+            }
+            set_local_indexes(loaded);
+            set_local_repodata(loaded_repodata);
+            set_local_crate_records(loaded_crate_records);
+        });
+    };
+
     view! {
         <div id="input-form">
+            <div class="input-row">
+                <span class="input-label">"convert"</span>
+                <input
+                    class="purl-component-input"
+                    type="text"
+                    placeholder="e.g. org.apache.commons:commons-lang3:3.12.0 or nginx:1.25"
+                    title="Maven coordinates, an npm package spec, a Cargo dependency, a pip requirement, a Docker image reference or a go.mod requirement"
+                    on:change=convert_input
+                />
+            </div>
+            {move || {
+                conversion()
+                    .map(|notation| {
+                        let note = match notation {
+                            Some(notation) => format!("read as {notation}"),
+                            None => "not a notation I know".to_string(),
+                        };
+                        view! {
+                            <div class="input-row">
+                                <span class="input-label"></span>
+                                <span class="conversion-note">{note}</span>
+                            </div>
+                        }
+                    })
+            }}
+
             <div class="input-row">
                 <span class="input-label">"type"</span>
                 {get_type_input_field}
@@ -543,11 +729,64 @@ fn MainContent() -> impl IntoView {
                     prop:value=move || urlencoding::decode(&name()).unwrap_or_default().into_owned()
                 />
             </div>
+            {move || {
+                let key = (typex(), namespace().join("/"), name());
+                let suggestions = name_suggestions
+                    .with(|suggestions| match suggestions {
+                        Some((suggestions_key, suggestions)) if *suggestions_key == key => {
+                            suggestions.clone()
+                        }
+                        _ => vec![],
+                    });
+                (!suggestions.is_empty())
+                    .then(|| {
+                        view! {
+                            <div class="input-row name-suggestions">
+                                <span class="input-label">"did you mean"</span>
+                                <div class="name-suggestion-list">
+                                    {suggestions
+                                        .into_iter()
+                                        .map(|suggestion| {
+                                            let label = match &suggestion.namespace {
+                                                Some(namespace) => {
+                                                    format!("{namespace}/{}", suggestion.name)
+                                                }
+                                                None => suggestion.name.clone(),
+                                            };
+                                            let title = suggestion.description.clone().unwrap_or_default();
+                                            view! {
+                                                <button
+                                                    class="name-suggestion"
+                                                    title=title
+                                                    on:click=move |_| {
+                                                        if let Some(namespace) = &suggestion.namespace {
+                                                            set_namespace(
+                                                                purl_data::PurlComponent::new_naive(namespace),
+                                                            );
+                                                        }
+                                                        set_name(
+                                                            urlencoding::encode(&suggestion.name).into_owned(),
+                                                        );
+                                                    }
+                                                >
+
+                                                    {label}
+                                                </button>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </div>
+                        }
+                    })
+            }}
+
             <div class="input-row">
                 <span class="input-label">"version"</span>
                 <input
                     class="purl-component-input"
                     type="text"
+                    list="version-candidates"
                     on:input=move |ev| {
                         set_version(
                             if !event_target_value(&ev).is_empty() {
@@ -565,6 +804,36 @@ fn MainContent() -> impl IntoView {
                     }
                 />
 
+                <datalist id="version-candidates">
+                    {move || {
+                        let key = (typex(), namespace().join("/"), name());
+                        version_candidates
+                            .with(|candidates| match candidates {
+                                Some((candidates_key, candidates)) if *candidates_key == key => {
+                                    candidates.clone()
+                                }
+                                _ => vec![],
+                            })
+                            .into_iter()
+                            .map(|candidate| {
+                                let label = if candidate.latest {
+                                    match &candidate.warning {
+                                        Some(warning) => format!("latest, {warning}"),
+                                        None => "latest".to_string(),
+                                    }
+                                } else {
+                                    candidate
+                                        .warning
+                                        .as_ref()
+                                        .map(|warning| warning.to_string())
+                                        .unwrap_or_default()
+                                };
+                                view! { <option value=candidate.version>{label}</option> }
+                            })
+                            .collect_view()
+                    }}
+
+                </datalist>
             </div>
             <div class="input-row">
                 <span class="input-label">"qualifiers"</span>
@@ -602,6 +871,223 @@ fn MainContent() -> impl IntoView {
                     prop:value=move || subpath().unwrap_or_default()
                 />
             </div>
+            <details id="settings">
+                <summary>"settings"</summary>
+                <div class="input-row">
+                    <span class="input-label">"GitHub token"</span>
+                    <input
+                        class="purl-component-input"
+                        type="password"
+                        placeholder="optional, stays in your browser"
+                        on:change=move |ev| set_github_token(event_target_value(&ev).trim().to_string())
+                        prop:value=github_token
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"GitLab URL"</span>
+                    <input
+                        class="purl-component-input"
+                        type="text"
+                        placeholder=purl_eval_gitlab::DEFAULT_BASE_URL
+                        on:change=move |ev| set_gitlab_base_url(event_target_value(&ev).trim().to_string())
+                        prop:value=gitlab_base_url
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"crates.io via"</span>
+                    <select
+                        class="purl-component-input"
+                        on:change=move |ev| set_cratesio_backend(event_target_value(&ev))
+                        prop:value=cratesio_backend
+                    >
+                        <option value="">"API"</option>
+                        <option value="sparse">"sparse index"</option>
+                    </select>
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"Hugging Face URL"</span>
+                    <input
+                        class="purl-component-input"
+                        type="text"
+                        placeholder=purl_eval_huggingface::DEFAULT_ENDPOINT
+                        on:change=move |ev| set_huggingface_endpoint(event_target_value(&ev).trim().to_string())
+                        prop:value=huggingface_endpoint
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"deb mirror"</span>
+                    <input
+                        class="purl-component-input"
+                        type="text"
+                        placeholder=purl_eval_deb::DEFAULT_DEBIAN_MIRROR
+                        on:change=move |ev| set_deb_mirror(event_target_value(&ev).trim().to_string())
+                        prop:value=deb_mirror
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"apk mirror"</span>
+                    <input
+                        class="purl-component-input"
+                        type="text"
+                        placeholder=purl_eval_apk::DEFAULT_MIRROR
+                        on:change=move |ev| set_apk_mirror(event_target_value(&ev).trim().to_string())
+                        prop:value=apk_mirror
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"index files"</span>
+                    <input
+                        class="purl-component-input"
+                        type="file"
+                        multiple
+                        title="Debian Packages/Sources, Alpine APKINDEX, conda repodata.json or crates index files, optionally gzipped"
+                        on:change=load_index_files
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"index directory"</span>
+                    <input
+                        class="purl-component-input"
+                        type="file"
+                        webkitdirectory
+                        title="a directory of index files, e.g. a checkout of a crates index"
+                        on:change=load_index_files
+                    />
+                </div>
+                <Show when=move || {
+                    local_indexes.with(|indexes| !indexes.is_empty())
+                        || local_repodata.with(|repodata| !repodata.is_empty())
+                        || local_crate_records.with(|records| !records.is_empty())
+                }>
+                    <div class="input-row">
+                        <span class="input-label"></span>
+                        <span>
+                            {move || {
+                                local_indexes
+                                    .with(|indexes| {
+                                        indexes
+                                            .iter()
+                                            .map(|(file_name, _, entries)| {
+                                                format!("{file_name} ({} packages)", entries.len())
+                                            })
+                                            .chain(
+                                                local_repodata
+                                                    .with(|repodata| {
+                                                        repodata
+                                                            .iter()
+                                                            .map(|(file_name, repodata)| {
+                                                                format!(
+                                                                    "{file_name} ({} packages)",
+                                                                    repodata.packages.len()
+                                                                        + repodata.packages_conda.len(),
+                                                                )
+                                                            })
+                                                            .collect::<Vec<_>>()
+                                                    }),
+                                            )
+                                            .chain(
+                                                local_crate_records
+                                                    .with(|records| {
+                                                        records
+                                                            .iter()
+                                                            .map(|(file_name, records)| {
+                                                                format!("{file_name} ({} versions)", records.len())
+                                                            })
+                                                            .collect::<Vec<_>>()
+                                                    }),
+                                            )
+                                            .join(", ")
+                                    })
+                            }}
+                        </span>
+                        <button on:click=move |_| {
+                            set_local_indexes(vec![]);
+                            set_local_repodata(vec![]);
+                            set_local_crate_records(vec![]);
+                        }>"clear"</button>
+                    </div>
+                </Show>
+                <div class="input-row">
+                    <span class="input-label">"cache TTL (min)"</span>
+                    <input
+                        class="purl-component-input"
+                        type="number"
+                        min="0"
+                        placeholder=purl_cache::CachePolicy::default().ttl.num_minutes().to_string()
+                        on:change=move |ev| set_cache_ttl_minutes(event_target_value(&ev).trim().to_string())
+                        prop:value=cache_ttl_minutes
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"not found TTL (min)"</span>
+                    <input
+                        class="purl-component-input"
+                        type="number"
+                        min="0"
+                        title="how long it is remembered that something does not exist"
+                        placeholder=purl_cache::CachePolicy::default()
+                            .negative_ttl
+                            .num_minutes()
+                            .to_string()
+                        on:change=move |ev| set_cache_negative_ttl_minutes(event_target_value(&ev).trim().to_string())
+                        prop:value=cache_negative_ttl_minutes
+                    />
+                    <button on:click=move |_| purl_cache::clear()>"clear cache"</button>
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"offline mode"</span>
+                    <input
+                        type="checkbox"
+                        title="do not send anything to registries or other online services"
+                        on:change=move |ev| {
+                            set_offline_mode(
+                                if event_target_checked(&ev) { "true" } else { "" }.to_string(),
+                            )
+                        }
+
+                        prop:checked=move || offline_mode() == "true"
+                    />
+                </div>
+                <div class="input-row">
+                    <span class="input-label">"online checks"</span>
+                    <div class="online-check-toggles">
+                        {CheckType::ALL
+                            .into_iter()
+                            .map(|check_type| {
+                                let id = check_type.id();
+                                let is_enabled = {
+                                    let id = id.clone();
+                                    move || !disabled_checks().split(',').any(|disabled| disabled == id)
+                                };
+                                view! {
+                                    <label class="online-check-toggle">
+                                        <input
+                                            type="checkbox"
+                                            prop:disabled=move || offline_mode() == "true"
+                                            prop:checked=is_enabled
+                                            on:change=move |ev| {
+                                                let enable = event_target_checked(&ev);
+                                                set_disabled_checks(
+                                                    disabled_checks
+                                                        .get_untracked()
+                                                        .split(',')
+                                                        .filter(|disabled| {
+                                                            !disabled.is_empty() && *disabled != id
+                                                        })
+                                                        .chain((!enable).then_some(id.as_str()))
+                                                        .join(","),
+                                                );
+                                            }
+                                        />
+
+                                        {check_type.to_string()}
+                                    </label>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                </div>
+            </details>
         </div>
 
         <Purl
@@ -619,6 +1105,36 @@ fn MainContent() -> impl IntoView {
             eval_subpath_result=eval_subpath_result
         />
 
+        {move || {
+            let urls = purl_url::urls(
+                &purl_data::Purl {
+                    typex: typex(),
+                    namespace: namespace(),
+                    name: name(),
+                    version: version(),
+                    qualifiers: qualifiers(),
+                    subpath: subpath(),
+                },
+            );
+            let links = [("package page", urls.web_url), ("download", urls.download_url)]
+                .into_iter()
+                .filter_map(|(label, url)| {
+                    url.map(|url| {
+                        view! {
+                            <a class="purl-link" href=url target="_blank" rel="noopener noreferrer">
+                                <phosphor_leptos::Link
+                                    class="button-icon"
+                                    weight=phosphor_leptos::IconWeight::Bold
+                                ></phosphor_leptos::Link>
+                                {label}
+                            </a>
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            (!links.is_empty()).then(|| view! { <div class="purl-links">{links}</div> })
+        }}
+
         <div class="explanation-box-wrapper">
             <div class="check-indicator">
                 <Show
@@ -652,6 +1168,142 @@ fn MainContent() -> impl IntoView {
                     </div>
                 </Show>
             </div>
+            <Show when=move || check_warning().is_some()>
+                <div class="explanation-box check-warning">
+                    <phosphor_leptos::Warning
+                        class="explanation-icon valid"
+                        weight=phosphor_leptos::IconWeight::Bold
+                    ></phosphor_leptos::Warning>
+                    <span class="headline">"could not complete check"</span>
+                    <span class="explanation">{move || check_warning().unwrap_or_default()}</span>
+                </div>
+            </Show>
+            <Show when=move || registry_metadata.with(|metadata| metadata.is_some())>
+                <div class="explanation-box registry-metadata">
+                    <phosphor_leptos::Package
+                        class="explanation-icon"
+                        weight=phosphor_leptos::IconWeight::Bold
+                    ></phosphor_leptos::Package>
+                    <span class="headline">
+                        {move || {
+                            registry_metadata
+                                .with(|metadata| {
+                                    metadata
+                                        .as_ref()
+                                        .map(|metadata| format!("on {}", metadata.source))
+                                        .unwrap_or_default()
+                                })
+                        }}
+                    </span>
+                    <dl class="explanation">
+                        {move || {
+                            registry_metadata
+                                .get()
+                                .map(|metadata| {
+                                    let warnings = metadata
+                                        .warnings
+                                        .iter()
+                                        .map(|warning| {
+                                            view! {
+                                                <dt class="metadata-warning">"note"</dt>
+                                                <dd class="metadata-warning">
+                                                    {format!("this version is {warning}")}
+                                                </dd>
+                                            }
+                                        })
+                                        .collect_view();
+                                    let fields = [
+                                        ("latest", metadata.latest_version),
+                                        ("license", metadata.license),
+                                        ("description", metadata.description),
+                                        (
+                                            "published",
+                                            metadata
+                                                .published
+                                                .map(|published| {
+                                                    published.format("%Y-%m-%d").to_string()
+                                                }),
+                                        ),
+                                    ]
+                                        .into_iter()
+                                        .filter_map(|(label, value)| {
+                                            value
+                                                .map(|value| {
+                                                    view! {
+                                                        <dt>{label}</dt>
+                                                        <dd>{value}</dd>
+                                                    }
+                                                })
+                                        })
+                                        .collect_view();
+                                    view! {
+                                        {warnings}
+                                        {fields}
+                                    }
+                                })
+                        }}
+                    </dl>
+                </div>
+            </Show>
+            {move || {
+                let purl = purl_data::Purl {
+                    typex: typex(),
+                    namespace: namespace(),
+                    name: name(),
+                    version: version(),
+                    qualifiers: qualifiers(),
+                    subpath: subpath(),
+                };
+                // only offered for packages the registry vouched for, pinned or not, or that look
+                // right where there is no registry to ask
+                let required = if purl_check::online_check(&purl, &Default::default()).is_some() {
+                    purl_eval::EvalResultLevel::Verified
+                } else {
+                    purl_eval::EvalResultLevel::ProbablyOk
+                };
+                let is_vouched_for = eval_name_result().at_least_as_good_as(&required)
+                    && (version().is_none()
+                        || eval_version_result().at_least_as_good_as(&required));
+                let snippets = is_vouched_for
+                    .then(|| purl_snippet::snippets(&purl))
+                    .unwrap_or_default();
+                (!snippets.is_empty())
+                    .then(|| {
+                        view! {
+                            <div class="explanation-box snippets">
+                                <phosphor_leptos::Code
+                                    class="explanation-icon"
+                                    weight=phosphor_leptos::IconWeight::Bold
+                                ></phosphor_leptos::Code>
+                                <span class="headline">"use it"</span>
+                                <dl class="explanation">
+                                    {snippets
+                                        .into_iter()
+                                        .map(|snippet| {
+                                            let code = snippet.code.clone();
+                                            view! {
+                                                <dt>{snippet.label}</dt>
+                                                <dd class="snippet-row">
+                                                    <pre class="snippet">{snippet.code}</pre>
+                                                    <button
+                                                        class="snippet-copy-button"
+                                                        title="copy to clipboard"
+                                                        on:click=move |_| copy_to_clipboard(&code)
+                                                    >
+                                                        <phosphor_leptos::Copy
+                                                            weight=phosphor_leptos::IconWeight::Bold
+                                                        ></phosphor_leptos::Copy>
+                                                    </button>
+                                                </dd>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </dl>
+                            </div>
+                        }
+                    })
+            }}
+
             <div class=get_type_explanation_box_class>
                 {move || match eval_type_result() {
                     purl_eval::EvalResultLevel::Verified => {
@@ -970,14 +1622,14 @@ fn Purl(
 
     let namespace_and_leading_slash = move || {
         let namespace_view = move || {
-            namespace()
-                .as_canonical()
-                .iter()
-                .map(|ns_part| view! { <span class="purl-namespace-part">{ns_part}</span> })
-                .intersperse_with(
-                    || view! { <span class="purl-sep namespace-inner-sep">"/"</span> },
-                )
-                .collect_view()
+            Itertools::intersperse_with(
+                namespace()
+                    .as_canonical()
+                    .iter()
+                    .map(|ns_part| view! { <span class="purl-namespace-part">{ns_part}</span> }),
+                || view! { <span class="purl-sep namespace-inner-sep">"/"</span> },
+            )
+            .collect_view()
         };
         view! {
             <Show when=move || !namespace().as_canonical().is_empty()>
@@ -1040,8 +1692,37 @@ fn Purl(
     }
 }
 
+/// Puts the text on the clipboard, through the asynchronous Clipboard API (which `web-sys` only
+/// binds behind its unstable APIs flag).
+fn copy_to_clipboard(text: &str) {
+    use leptos::wasm_bindgen::JsCast;
+
+    let write_text = window()
+        .navigator()
+        .dyn_into::<js_sys::Object>()
+        .ok()
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &"clipboard".into()).ok())
+        .filter(|clipboard| !clipboard.is_undefined())
+        .and_then(|clipboard| {
+            let write_text = js_sys::Reflect::get(&clipboard, &"writeText".into()).ok()?;
+            let write_text = write_text.dyn_into::<js_sys::Function>().ok()?;
+            write_text.call1(&clipboard, &text.into()).ok()
+        });
+    let Some(promise) = write_text.and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+    else {
+        log::warn!("the clipboard is not available");
+        return;
+    };
+    spawn_local(async move {
+        if let Err(e) = wasm_bindgen_futures::JsFuture::from(promise).await {
+            log::warn!("could not copy to the clipboard ({e:?})");
+        }
+    });
+}
+
 fn main() {
     _ = console_log::init_with_level(log::Level::Debug);
     console_error_panic_hook::set_once();
+    purl_cache::set_cache(std::rc::Rc::new(purl_cache::LocalStorageCache::default()));
     leptos::mount_to_body(|| view! { <App/> })
 }
@@ -0,0 +1,408 @@
+use futures::StreamExt;
+use leptos::*;
+use leptos_use::storage::{use_local_storage, StringCodec};
+
+use purl_core::purl_batch::{self, BatchEntry, BatchSummary};
+use purl_core::purl_fix::{self, FixedSbom};
+use purl_core::purl_lockfile::{self, LockfileFormat};
+use purl_core::{purl_eval, purl_sbom};
+use purl_verify::purl_check;
+
+use crate::purl_generation;
+
+/// How many of the entries that need attention are listed, the reports have all of them.
+const MAX_FINDINGS: usize = 500;
+
+/// Whether the entry needs attention: it is not at least probably ok, or there is more to it.
+fn is_finding(entry: &BatchEntry) -> bool {
+    entry
+        .level()
+        .more_severe_than(&purl_eval::EvalResultLevel::ProbablyOk)
+        || !entry.warnings.is_empty()
+}
+
+/// Validates a whole list of purls, e.g. the output of a scanner.
+#[component]
+pub fn BulkContent(
+    local_indexes: ReadSignal<crate::LocalIndexes>,
+    local_repodata: ReadSignal<crate::LocalRepodata>,
+    local_crate_records: ReadSignal<crate::LocalCrateRecords>,
+) -> impl IntoView {
+    let (input, set_input) = create_signal(String::new());
+    // the CSV column holding the purls; newline-separated input when empty
+    let (column, set_column) = create_signal(String::new());
+    let (online, set_online) = create_signal(false);
+    let (concurrency, set_concurrency) = create_signal(4_usize);
+    let (entries, set_entries) = create_signal::<Vec<BatchEntry>>(vec![]);
+    let (pending_checks, set_pending_checks) = create_signal(0_usize);
+    let (input_error, set_input_error) = create_signal::<Option<String>>(None);
+    let (fixed, set_fixed) = create_signal::<Option<FixedSbom>>(None);
+    // the name of the loaded file, which tells the format of lockfiles
+    let (file_name, set_file_name) = create_signal::<Option<String>>(None);
+
+    // the same settings the builder uses
+    let (github_token, _, _) = use_local_storage::<String, StringCodec>("github-token");
+    let (gitlab_base_url, _, _) = use_local_storage::<String, StringCodec>("gitlab-base-url");
+    let (huggingface_endpoint, _, _) =
+        use_local_storage::<String, StringCodec>("huggingface-endpoint");
+    let (deb_mirror, _, _) = use_local_storage::<String, StringCodec>("deb-mirror");
+    let (apk_mirror, _, _) = use_local_storage::<String, StringCodec>("apk-mirror");
+    let (cratesio_backend, _, _) = use_local_storage::<String, StringCodec>("cratesio-backend");
+    let (offline_mode, _, _) = use_local_storage::<String, StringCodec>("offline-mode");
+    let (disabled_checks, _, _) = use_local_storage::<String, StringCodec>("disabled-checks");
+
+    let load_file = move |ev: ev::Event| {
+        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match wasm_bindgen_futures::JsFuture::from(file.text()).await {
+                Ok(text) => {
+                    set_input(text.as_string().unwrap_or_default());
+                    set_file_name(Some(file.name()));
+                    set_fixed(None);
+                }
+                Err(e) => {
+                    set_input_error(Some(format!("could not read '{}' ({e:?})", file.name())))
+                }
+            }
+        });
+    };
+
+    // a run that is started while another is still checking makes the older one's results stale
+    let runs = purl_generation::Generations::new();
+    let run = move |_| {
+        let generation = runs.start();
+
+        let column = Some(column.get_untracked()).filter(|column| !column.trim().is_empty());
+        let lockfile_format = file_name
+            .get_untracked()
+            .and_then(|name| LockfileFormat::of_file_name(&name));
+        let read = input.with_untracked(|input| {
+            if purl_sbom::is_sbom(input) {
+                purl_sbom::read(input)
+                    .map(|components| purl_sbom::entries(&components))
+                    .map_err(|e| e.to_string())
+            } else if let Some(format) = lockfile_format.or_else(|| LockfileFormat::sniff(input)) {
+                purl_lockfile::read(input, format)
+                    .map(|purls| {
+                        purls
+                            .iter()
+                            .map(|purl| BatchEntry::evaluate(&purl.to_string()))
+                            .collect()
+                    })
+                    .map_err(|e| e.to_string())
+            } else {
+                purl_batch::read_purls(input, column.as_deref())
+                    .map(|inputs| {
+                        inputs
+                            .iter()
+                            .map(|input| BatchEntry::evaluate(input))
+                            .collect()
+                    })
+                    .map_err(|e| e.to_string())
+            }
+        });
+        match read {
+            Ok(read) => set_entries(read),
+            Err(e) => {
+                set_input_error(Some(e));
+                return;
+            }
+        }
+        set_input_error(None);
+        set_pending_checks(0);
+        if !online.get_untracked() || offline_mode.get_untracked() == "true" {
+            return;
+        }
+
+        let options = purl_check::CheckOptions {
+            github_token: Some(github_token.get_untracked()).filter(|t| !t.is_empty()),
+            gitlab_base_url: Some(gitlab_base_url.get_untracked()).filter(|url| !url.is_empty()),
+            huggingface_endpoint: Some(huggingface_endpoint.get_untracked())
+                .filter(|url| !url.is_empty()),
+            deb_mirror: Some(deb_mirror.get_untracked()).filter(|mirror| !mirror.is_empty()),
+            apk_mirror: Some(apk_mirror.get_untracked()).filter(|mirror| !mirror.is_empty()),
+            cratesio_sparse_index: cratesio_backend.get_untracked() == "sparse",
+            // the same index files as for single checks, so purls get verified the same way
+            local_indexes: local_indexes.with_untracked(|indexes| {
+                indexes
+                    .iter()
+                    .map(|(_, format, entries)| (*format, entries.clone()))
+                    .collect()
+            }),
+            local_repodata: local_repodata.with_untracked(|files| {
+                files.iter().map(|(_, repodata)| repodata.clone()).collect()
+            }),
+            local_crate_records: local_crate_records
+                .with_untracked(|files| files.iter().map(|(_, records)| records.clone()).collect()),
+        };
+        let disabled_checks = disabled_checks.get_untracked();
+        let to_check = entries.with_untracked(|entries| {
+            entries
+                .iter()
+                .enumerate()
+                .filter_map(|(i, entry)| Some((i, entry.purl.clone().ok()?)))
+                .filter(|(_, purl)| {
+                    purl_check::online_check(purl, &options).is_some_and(|check_type| {
+                        !disabled_checks.split(',').any(|id| id == check_type.id())
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+        set_pending_checks(to_check.len());
+        let concurrency = concurrency.get_untracked().max(1);
+        spawn_local(async move {
+            futures::stream::iter(to_check)
+                .map(|(i, purl)| {
+                    let options = options.clone();
+                    async move { (i, purl_check::verify(&purl, &options).await) }
+                })
+                .buffer_unordered(concurrency)
+                .for_each(|(i, verification)| async move {
+                    if !generation.is_current() {
+                        return;
+                    }
+                    set_entries.update(|entries| {
+                        entries[i].results = Some(verification.results);
+                        entries[i].warnings.extend(verification.warnings);
+                    });
+                    set_pending_checks.update(|pending| *pending -= 1);
+                })
+                .await
+        });
+    };
+
+    let is_sbom = create_memo(move |_| input.with(|input| purl_sbom::is_sbom(input)));
+    let fix = move |_| match input.with_untracked(|input| purl_fix::fix(input)) {
+        Ok(fixed) => {
+            set_input_error(None);
+            set_fixed(Some(fixed));
+        }
+        Err(e) => set_input_error(Some(e.to_string())),
+    };
+    let fixed_url = move || {
+        fixed.with(|fixed| {
+            fixed.as_ref().map(|fixed| {
+                format!(
+                    "data:text/plain;charset=utf-8,{}",
+                    urlencoding::encode(&fixed.content)
+                )
+            })
+        })
+    };
+
+    let summary = create_memo(move |_| entries.with(|entries| BatchSummary::of(entries)));
+    // only built once all checks are done, rather than again for every check that completes
+    let report_url = move |mime: &str, report: fn(&[BatchEntry]) -> String| {
+        if pending_checks() > 0 {
+            return None;
+        }
+        let report = entries.with(|entries| report(entries));
+        Some(format!(
+            "data:{mime};charset=utf-8,{}",
+            urlencoding::encode(&report)
+        ))
+    };
+
+    view! {
+        <div id="bulk-content">
+            <textarea
+                class="bulk-input"
+                placeholder="one purl per line, CSV with the column given below, a CycloneDX or SPDX SBOM, or a lockfile (Cargo.lock, package-lock.json, yarn.lock, pnpm-lock.yaml, poetry.lock, requirements.txt, go.sum)"
+                on:input=move |ev| {
+                    set_input(event_target_value(&ev));
+                    set_file_name(None);
+                    set_fixed(None);
+                }
+                prop:value=input
+            ></textarea>
+            <div class="input-row">
+                <span class="input-label">"file"</span>
+                <input class="purl-component-input" type="file" on:change=load_file/>
+            </div>
+            <div class="input-row">
+                <span class="input-label">"CSV column"</span>
+                <input
+                    class="purl-component-input"
+                    type="text"
+                    placeholder="e.g. purl; leave empty for one purl per line"
+                    on:input=move |ev| set_column(event_target_value(&ev))
+                    prop:value=column
+                />
+            </div>
+            <div class="input-row">
+                <span class="input-label">"online"</span>
+                <label>
+                    <input
+                        type="checkbox"
+                        on:change=move |ev| set_online(event_target_checked(&ev))
+                        prop:checked=online
+                    />
+                    "check against the registries, "
+                    <input
+                        class="bulk-concurrency"
+                        type="number"
+                        min="1"
+                        max="32"
+                        on:change=move |ev| {
+                            if let Ok(n) = event_target_value(&ev).parse::<usize>() {
+                                set_concurrency(n.clamp(1, 32))
+                            }
+                        }
+                        prop:value=move || concurrency().to_string()
+                    />
+                    " at a time"
+                </label>
+            </div>
+            <div class="bulk-downloads">
+                <button on:click=run>"validate"</button>
+                <Show when=is_sbom>
+                    <button on:click=fix>"fix purls"</button>
+                </Show>
+            </div>
+            <Show when=move || input_error().is_some()>
+                <div class="explanation-box invalid">{move || input_error().unwrap_or_default()}</div>
+            </Show>
+            <Show when=move || fixed.with(Option::is_some)>
+                <div class="bulk-downloads">
+                    <span>
+                        {move || {
+                            fixed
+                                .with(|fixed| {
+                                    fixed.as_ref().map(|fixed| fixed.changes.len()).unwrap_or_default()
+                                })
+                        }}
+                        " purls changed"
+                    </span>
+                    <a
+                        download=move || {
+                            fixed
+                                .with(|fixed| {
+                                    format!(
+                                        "sbom-fixed.{}",
+                                        fixed.as_ref().map(|fixed| fixed.extension).unwrap_or("txt"),
+                                    )
+                                })
+                        }
+                        href=fixed_url
+                    >
+                        "fixed SBOM"
+                    </a>
+                </div>
+                <table class="bulk-findings">
+                    {move || {
+                        fixed
+                            .with(|fixed| {
+                                fixed
+                                    .iter()
+                                    .flat_map(|fixed| fixed.changes.iter().take(MAX_FINDINGS).cloned())
+                                    .collect::<Vec<_>>()
+                            })
+                            .into_iter()
+                            .map(|change| {
+                                view! {
+                                    <tr>
+                                        <td>{change.reference}</td>
+                                        <td class="bulk-finding-purl">{change.before}</td>
+                                        <td class="bulk-finding-purl">{change.after}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </table>
+            </Show>
+            <Show when=move || (pending_checks() > 0)>
+                <div>{move || format!("{} online checks pending", pending_checks())}</div>
+            </Show>
+            <Show when=move || (summary.with(|summary| summary.total) > 0)>
+                <table class="bulk-summary">
+                    <tr>
+                        <th>"type"</th>
+                        {purl_batch::LEVELS
+                            .iter()
+                            .map(|level| view! { <th class=level.to_string()>{level.to_string()}</th> })
+                            .collect_view()}
+                        <th>"total"</th>
+                    </tr>
+                    {move || {
+                        summary
+                            .with(|summary| {
+                                summary
+                                    .types
+                                    .iter()
+                                    .map(|(typex, levels)| (typex.clone(), levels.clone()))
+                                    .chain([("all".to_string(), summary.levels.clone())])
+                                    .collect::<Vec<_>>()
+                            })
+                            .into_iter()
+                            .map(|(typex, levels)| {
+                                view! {
+                                    <tr>
+                                        <td>{typex}</td>
+                                        {purl_batch::LEVELS
+                                            .iter()
+                                            .map(|level| {
+                                                view! {
+                                                    <td>{levels.get(level).copied().unwrap_or_default()}</td>
+                                                }
+                                            })
+                                            .collect_view()}
+                                        <td>{levels.values().sum::<usize>()}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </table>
+                <div class="bulk-downloads">
+                    <a
+                        download="purl-report.json"
+                        href=move || report_url("application/json", purl_batch::report_json)
+                    >
+                        "report (JSON)"
+                    </a>
+                    <a
+                        download="purl-report.csv"
+                        href=move || report_url("text/csv", purl_batch::report_csv)
+                    >
+                        "report (CSV)"
+                    </a>
+                </div>
+                <table class="bulk-findings">
+                    {move || {
+                        entries
+                            .with(|entries| {
+                                entries
+                                    .iter()
+                                    .filter(|entry| is_finding(entry))
+                                    .take(MAX_FINDINGS)
+                                    .cloned()
+                                    .collect::<Vec<_>>()
+                            })
+                            .into_iter()
+                            .map(|entry| {
+                                let level = entry.level();
+                                let explanation = [entry.explanation()]
+                                    .into_iter()
+                                    .chain(entry.warnings.iter().cloned())
+                                    .collect::<Vec<_>>()
+                                    .join("; ");
+                                view! {
+                                    <tr>
+                                        <td>{entry.reference.clone().unwrap_or_default()}</td>
+                                        <td class="bulk-finding-purl">{entry.input.clone()}</td>
+                                        <td class=format!("explanation-box {level}")>{level.to_string()}</td>
+                                        <td>{explanation}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </table>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,74 @@
+use leptos::{store_value, StoredValue};
+
+/// Numbers the checks started for successive inputs, so that a check whose input has been
+/// replaced in the meantime can tell that its results are stale.
+#[derive(Clone, Copy)]
+pub struct Generations {
+    latest: StoredValue<u64>,
+}
+
+impl Generations {
+    pub fn new() -> Self {
+        Generations {
+            latest: store_value(0),
+        }
+    }
+
+    /// Starts a new generation, which makes all earlier ones stale.
+    pub fn start(&self) -> Generation {
+        self.latest.update_value(|latest| *latest += 1);
+        Generation {
+            latest: self.latest,
+            id: self.latest.get_value(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Generation {
+    latest: StoredValue<u64>,
+    id: u64,
+}
+
+impl Generation {
+    pub fn is_current(&self) -> bool {
+        self.latest.get_value() == self.id
+    }
+
+    /// Wraps a setter so that it does nothing once a newer generation has started.
+    pub fn guard<T>(self, set: impl Fn(T) + Copy) -> impl Fn(T) + Copy {
+        move |value| {
+            if self.is_current() {
+                set(value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::{create_runtime, create_signal, SignalGetUntracked};
+
+    use super::Generations;
+
+    #[test]
+    fn test_out_of_order_responses_are_discarded() {
+        let runtime = create_runtime();
+        let (result, set_result) = create_signal("");
+        let generations = Generations::new();
+
+        let first = generations.start();
+        let set_first_result = first.guard(set_result);
+        let second = generations.start();
+        let set_second_result = second.guard(set_result);
+
+        // the check for the newer input finishes first, the one for the older input after it
+        set_second_result("second");
+        set_first_result("first");
+        assert_eq!(result.get_untracked(), "second");
+        assert!(!first.is_current());
+        assert!(second.is_current());
+
+        runtime.dispose();
+    }
+}